@@ -0,0 +1,61 @@
+//! Registry events added since the original event set. Each is published
+//! under its short symbol topic with its fields as a map keyed by field name.
+//! The original events still publish positional tuples through
+//! `env.events().publish`.
+
+use soroban_sdk::{contractevent, Address, BytesN, String};
+
+/// The registry admin was set.
+#[contractevent(topics = ["init"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Initialized {
+    pub admin: Address,
+    pub version: String,
+}
+
+/// The registry admin replaced the contract code.
+#[contractevent(topics = ["upgraded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// `migrate` rewrote every stored group; the schema is now at `version`.
+#[contractevent(topics = ["migrated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Migrated {
+    pub version: String,
+}
+
+/// The registry admin switched on factory mode.
+#[contractevent(topics = ["factory"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactorySet {
+    pub wasm_hash: BytesN<32>,
+    pub native_token: Address,
+}
+
+/// A savings contract reported a join or removal.
+#[contractevent(topics = ["sync_mem"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberSynced {
+    pub contract_address: Address,
+    pub member: Address,
+    pub joined: bool,
+}
+
+/// A savings contract reported an admin transfer.
+#[contractevent(topics = ["sync_adm"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminSynced {
+    pub contract_address: Address,
+    pub new_admin: Address,
+}
+
+/// A savings contract reported a cancellation or completion.
+#[contractevent(topics = ["sync_cls"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClosedSynced {
+    pub contract_address: Address,
+    pub cancelled: bool,
+}
@@ -1,11 +1,21 @@
 #![no_std]
+// `create_group_contract` forwards `create_group`'s ten arguments, and the
+// generated client and test imports repeat them.
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    Map, String, Symbol, TryFromVal, Val, Vec,
 };
+use esustellar_savings::{Frequency, GroupOptions, SavingsContractClient};
 
-// #697: Contract version for schema migration tracking.
-pub const CONTRACT_VERSION: &str = "0.1.0";
+mod events;
+
+// #697: Contract version for schema migration tracking. The version whose
+// schema the stored records follow is kept under `DataKey::Version`.
+pub const CONTRACT_VERSION: &str = "0.3.0";
+// Deployments from before `DataKey::Version` existed.
+pub const LEGACY_CONTRACT_VERSION: &str = "0.1.0";
 
 // #696: Registry error codes start at 100 to avoid overlap with savings contract codes.
 #[contracterror]
@@ -17,6 +27,10 @@ pub enum Error {
     NotGroupAdmin = 102,
     UserNotInGroup = 103,
     InvalidAddress = 104,
+    NotAdmin = 105,
+    AlreadyInitialized = 106,
+    FactoryNotConfigured = 107,
+    NotFactoryDeployed = 108,
 }
 
 #[contracttype]
@@ -29,6 +43,21 @@ pub struct GroupInfo {
     pub is_public: bool,
     pub created_at: u64,
     pub total_members: u32,
+    /// Set once the savings contract reports the group cancelled or completed.
+    pub closed: bool,
+}
+
+/// `GroupInfo` as stored before 0.3.0, read only by `migrate`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyGroupInfo {
+    pub contract_address: Address,
+    pub group_id: String,
+    pub name: String,
+    pub admin: Address,
+    pub is_public: bool,
+    pub created_at: u64,
+    pub total_members: u32,
 }
 
 #[contracttype]
@@ -39,6 +68,12 @@ pub enum DataKey {
     GroupInfo(Address),
     GroupCount,
     RegisteredGroupId(String),
+    Admin,
+    Version,
+    MigrationCursor,
+    SavingsWasm,
+    NativeToken,
+    Deployed(Address),
 }
 
 const PAGE_SIZE: u32 = 100;
@@ -48,9 +83,112 @@ pub struct GroupRegistry;
 
 #[contractimpl]
 impl GroupRegistry {
+    /// Set the registry admin, who is allowed to upgrade and migrate the
+    /// contract. Group registration does not depend on it.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().persistent().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        let version = String::from_str(&env, CONTRACT_VERSION);
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage().persistent().set(&DataKey::Version, &version);
+
+        events::Initialized { admin, version }.publish(&env);
+        Ok(())
+    }
+
+    /// Replace the registry's code with an already-uploaded WASM blob.
+    /// Only callable by the registry admin; follow up with `migrate`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::Upgraded { new_wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Rewrite `GroupInfo` records stored by an older contract version, at
+    /// most `batch_size` per call. Converts pre-0.3.0 records (no `closed`
+    /// flag), backfills the `RegisteredGroupId` index for groups registered
+    /// before it existed and extends record TTLs.
+    /// Returns the number of groups still to be migrated; once it reaches 0
+    /// the stored schema version is set to `CONTRACT_VERSION`.
+    pub fn migrate(env: Env, admin: Address, batch_size: u32) -> Result<u32, Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let current = String::from_str(&env, CONTRACT_VERSION);
+        if Self::schema_version(&env) == current {
+            return Ok(0);
+        }
+
+        let all_groups: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AllGroups)
+            .unwrap_or(Vec::new(&env));
+        let start: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(0);
+        let end = core::cmp::min(start.saturating_add(batch_size), all_groups.len());
+
+        for i in start..end {
+            let contract_address = all_groups.get(i).unwrap();
+            let key = DataKey::GroupInfo(contract_address.clone());
+            let raw: Option<Map<Symbol, Val>> = env.storage().persistent().get(&key);
+            if let Some(raw) = raw {
+                // Pre-0.3.0 records have no `closed` field.
+                let group_info = if raw.contains_key(Symbol::new(&env, "closed")) {
+                    GroupInfo::try_from_val(&env, &raw.to_val()).map_err(|_| Error::GroupNotFound)?
+                } else {
+                    let legacy = LegacyGroupInfo::try_from_val(&env, &raw.to_val())
+                        .map_err(|_| Error::GroupNotFound)?;
+                    GroupInfo {
+                        contract_address: legacy.contract_address,
+                        group_id: legacy.group_id,
+                        name: legacy.name,
+                        admin: legacy.admin,
+                        is_public: legacy.is_public,
+                        created_at: legacy.created_at,
+                        total_members: legacy.total_members,
+                        closed: false,
+                    }
+                };
+                let id_key = DataKey::RegisteredGroupId(group_info.group_id.clone());
+                if !env.storage().persistent().has(&id_key) {
+                    env.storage().persistent().set(&id_key, &contract_address);
+                }
+                env.storage().persistent().set(&key, &group_info);
+                env.storage().persistent().extend_ttl(&key, 6_312_000, 6_312_000);
+                env.storage().persistent().extend_ttl(&id_key, 6_312_000, 6_312_000);
+            }
+        }
+
+        if end < all_groups.len() {
+            env.storage().persistent().set(&DataKey::MigrationCursor, &end);
+            return Ok(all_groups.len() - end);
+        }
+
+        env.storage().persistent().remove(&DataKey::MigrationCursor);
+        env.storage().persistent().set(&DataKey::Version, &current);
+        events::Migrated { version: current }.publish(&env);
+        Ok(0)
+    }
+
+    /// Get the contract version whose schema the stored records follow.
+    pub fn get_schema_version(env: Env) -> String {
+        Self::schema_version(&env)
+    }
+
     /// Register a savings group in the registry.
     /// Verifies the contract address is a real deployed savings contract that
-    /// knows about the group_id and that the admin matches.
+    /// knows about the group_id and that the admin matches. Once factory mode
+    /// is configured, only contracts deployed by `create_group_contract` are
+    /// accepted.
     pub fn register_group(
         env: Env,
         contract_address: Address,
@@ -62,6 +200,138 @@ impl GroupRegistry {
     ) -> Result<(), Error> {
         admin.require_auth();
 
+        if env.storage().persistent().has(&DataKey::SavingsWasm)
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Deployed(contract_address.clone()))
+        {
+            return Err(Error::NotFactoryDeployed);
+        }
+
+        Self::register(&env, contract_address, group_id, name, admin, is_public, total_members)
+    }
+
+    /// Enable factory mode: `create_group_contract` deploys this savings WASM
+    /// (already uploaded) once per group, initialized with `native_token` as
+    /// its native XLM contract. Only callable by the registry admin.
+    pub fn set_factory(
+        env: Env,
+        admin: Address,
+        wasm_hash: BytesN<32>,
+        native_token: Address,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().persistent().set(&DataKey::SavingsWasm, &wasm_hash);
+        env.storage().persistent().set(&DataKey::NativeToken, &native_token);
+
+        events::FactorySet { wasm_hash, native_token }.publish(&env);
+        Ok(())
+    }
+
+    /// Deploy a dedicated savings contract for a new group, then initialize
+    /// it, create the group and register it, all in one call. Each group's
+    /// funds live in their own contract.
+    ///
+    /// The contract address is derived from `group_id`, so it can be
+    /// computed ahead of time with `get_group_contract_address`. The registry
+    /// is the deployed contract's admin and is set as its sync target.
+    /// Returns the new contract's address.
+    pub fn create_group_contract(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        name: String,
+        contribution_amount: i128,
+        total_members: u32,
+        frequency: Frequency,
+        start_timestamp: u64,
+        is_public: bool,
+        treasury: Address,
+        options: Option<GroupOptions>,
+    ) -> Result<Address, Error> {
+        admin.require_auth();
+
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SavingsWasm)
+            .ok_or(Error::FactoryNotConfigured)?;
+        let native_token: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NativeToken)
+            .ok_or(Error::FactoryNotConfigured)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RegisteredGroupId(group_id.clone()))
+        {
+            return Err(Error::GroupAlreadyRegistered);
+        }
+
+        let contract_address = env
+            .deployer()
+            .with_current_contract(Self::group_salt(&env, &group_id))
+            .deploy_v2(wasm_hash, ());
+
+        let registry = env.current_contract_address();
+        let savings = SavingsContractClient::new(&env, &contract_address);
+        savings.initialize(&registry, &native_token);
+        savings.set_registry(&registry, &Some(registry.clone()));
+        savings.create_group(
+            &admin,
+            &group_id,
+            &name,
+            &contribution_amount,
+            &total_members,
+            &frequency,
+            &start_timestamp,
+            &is_public,
+            &treasury,
+            &options,
+        );
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Deployed(contract_address.clone()), &true);
+        Self::register(
+            &env,
+            contract_address.clone(),
+            group_id,
+            name,
+            admin,
+            is_public,
+            total_members,
+        )?;
+
+        Ok(contract_address)
+    }
+
+    /// Address `create_group_contract` deploys (or deployed) `group_id` at.
+    pub fn get_group_contract_address(env: Env, group_id: String) -> Address {
+        env.deployer()
+            .with_current_contract(Self::group_salt(&env, &group_id))
+            .deployed_address()
+    }
+
+    /// Whether `contract_address` was deployed by this registry's factory.
+    pub fn is_factory_deployed(env: Env, contract_address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Deployed(contract_address))
+    }
+
+    fn register(
+        env: &Env,
+        contract_address: Address,
+        group_id: String,
+        name: String,
+        admin: Address,
+        is_public: bool,
+        total_members: u32,
+    ) -> Result<(), Error> {
         if env
             .storage()
             .persistent()
@@ -74,7 +344,7 @@ impl GroupRegistry {
             return Err(Error::GroupAlreadyRegistered);
         }
 
-        let savings_group = esustellar_savings::SavingsContractClient::new(&env, &contract_address)
+        let savings_group = SavingsContractClient::new(env, &contract_address)
             .try_get_group(&group_id)
             .map_err(|_| Error::InvalidAddress)?
             .map_err(|_| Error::InvalidAddress)?;
@@ -90,6 +360,7 @@ impl GroupRegistry {
             is_public,
             created_at: env.ledger().timestamp(),
             total_members,
+            closed: false,
         };
 
         env.storage()
@@ -108,7 +379,7 @@ impl GroupRegistry {
             .storage()
             .persistent()
             .get(&DataKey::AllGroups)
-            .unwrap_or(Vec::new(&env));
+            .unwrap_or(Vec::new(env));
         all_groups.push_back(contract_address.clone());
         env.storage()
             .persistent()
@@ -127,7 +398,7 @@ impl GroupRegistry {
             .storage()
             .persistent()
             .get(&DataKey::UserGroups(admin.clone()))
-            .unwrap_or(Vec::new(&env));
+            .unwrap_or(Vec::new(env));
         admin_groups.push_back(contract_address.clone());
         env.storage()
             .persistent()
@@ -235,6 +506,75 @@ impl GroupRegistry {
         Ok(())
     }
 
+    /// Sync a join or removal reported by the group's savings contract.
+    ///
+    /// Privileged counterpart of `add_member`/`remove_member`: authorized by
+    /// the savings contract itself rather than the member, so the contract
+    /// can push its own state changes. `member_count` is the group's member
+    /// count after the change and replaces `total_members`. Calls for a group
+    /// that is not registered at `contract_address` are ignored.
+    pub fn sync_member(
+        env: Env,
+        contract_address: Address,
+        group_id: String,
+        member: Address,
+        joined: bool,
+        member_count: u32,
+    ) {
+        contract_address.require_auth();
+
+        let Some(mut group_info) = Self::synced_group(&env, &contract_address, &group_id) else {
+            return;
+        };
+        group_info.total_members = member_count;
+        env.storage()
+            .persistent()
+            .set(&DataKey::GroupInfo(contract_address.clone()), &group_info);
+
+        if joined {
+            Self::add_user_group(&env, &member, &contract_address);
+        } else {
+            Self::remove_user_group(&env, &member, &contract_address);
+        }
+
+        events::MemberSynced { contract_address, member, joined }.publish(&env);
+    }
+
+    /// Sync an admin transfer reported by the group's savings contract.
+    /// Calls for a group that is not registered at `contract_address` are ignored.
+    pub fn sync_admin(env: Env, contract_address: Address, group_id: String, new_admin: Address) {
+        contract_address.require_auth();
+
+        let Some(mut group_info) = Self::synced_group(&env, &contract_address, &group_id) else {
+            return;
+        };
+        group_info.admin = new_admin.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::GroupInfo(contract_address.clone()), &group_info);
+        Self::add_user_group(&env, &new_admin, &contract_address);
+
+        events::AdminSynced { contract_address, new_admin }.publish(&env);
+    }
+
+    /// Sync a cancellation (`cancelled = true`) or completion reported by the
+    /// group's savings contract. Closed groups stay registered but are left
+    /// out of the public group listings. Calls for a group that is not
+    /// registered at `contract_address` are ignored.
+    pub fn sync_closed(env: Env, contract_address: Address, group_id: String, cancelled: bool) {
+        contract_address.require_auth();
+
+        let Some(mut group_info) = Self::synced_group(&env, &contract_address, &group_id) else {
+            return;
+        };
+        group_info.closed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::GroupInfo(contract_address.clone()), &group_info);
+
+        events::ClosedSynced { contract_address, cancelled }.publish(&env);
+    }
+
     /// Update the mutable metadata for a registered group.
     pub fn update_group_info(
         env: Env,
@@ -462,7 +802,7 @@ impl GroupRegistry {
                     .persistent()
                     .get::<DataKey, GroupInfo>(&DataKey::GroupInfo(group_addr))
                 {
-                    if group_info.is_public {
+                    if group_info.is_public && !group_info.closed {
                         public_groups.push_back(group_info);
                     }
                 }
@@ -488,7 +828,7 @@ impl GroupRegistry {
                     .persistent()
                     .get::<DataKey, GroupInfo>(&DataKey::GroupInfo(group_addr))
                 {
-                    if group_info.is_public {
+                    if group_info.is_public && !group_info.closed {
                         public_groups.push_back(group_info);
                     }
                 }
@@ -589,7 +929,7 @@ impl GroupRegistry {
                     .persistent()
                     .get::<DataKey, GroupInfo>(&DataKey::GroupInfo(group_addr))
                 {
-                    if !group_info.is_public {
+                    if !group_info.is_public || group_info.closed {
                         continue;
                     }
                     let mut matches = true;
@@ -628,7 +968,12 @@ impl GroupRegistry {
         result
     }
 
-    pub fn get_all_groups_info_page_filtered(
+    /// Page through every group, filtered by admin and member count.
+    ///
+    /// Formerly `get_all_groups_info_page_filtered`; that name is 33
+    /// characters, over Soroban's 32-character function name limit, so the
+    /// registry did not build with it.
+    pub fn get_groups_info_page_filtered(
         env: Env,
         page: u32,
         page_size: u32,
@@ -686,6 +1031,73 @@ impl GroupRegistry {
         }
         result
     }
+
+    fn schema_version(env: &Env) -> String {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Version)
+            .unwrap_or(String::from_str(env, LEGACY_CONTRACT_VERSION))
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotAdmin)?;
+        if stored != *admin {
+            return Err(Error::NotAdmin);
+        }
+        Ok(())
+    }
+
+    /// The registered `GroupInfo` for `group_id`, if it lives at `contract_address`.
+    fn synced_group(env: &Env, contract_address: &Address, group_id: &String) -> Option<GroupInfo> {
+        let registered: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RegisteredGroupId(group_id.clone()))?;
+        if registered != *contract_address {
+            return None;
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::GroupInfo(contract_address.clone()))
+    }
+
+    fn add_user_group(env: &Env, user: &Address, contract_address: &Address) {
+        let mut user_groups: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserGroups(user.clone()))
+            .unwrap_or(Vec::new(env));
+        if !user_groups.contains(contract_address) {
+            user_groups.push_back(contract_address.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::UserGroups(user.clone()), &user_groups);
+        }
+    }
+
+    fn remove_user_group(env: &Env, user: &Address, contract_address: &Address) {
+        let mut user_groups: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserGroups(user.clone()))
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = user_groups.first_index_of(contract_address) {
+            user_groups.remove(idx);
+            env.storage()
+                .persistent()
+                .set(&DataKey::UserGroups(user.clone()), &user_groups);
+        }
+    }
+
+    /// Deterministic deployment salt for a group's savings contract.
+    fn group_salt(env: &Env, group_id: &String) -> BytesN<32> {
+        env.crypto().sha256(&group_id.to_bytes()).into()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,487 @@
+//! Contract events added since the original event set. Each is published
+//! under the short symbol topic listed in `docs/event-schema.md`, with its
+//! fields as a map keyed by field name. The original events still publish
+//! positional tuples through `env.events().publish`.
+
+use soroban_sdk::{contractevent, Address, BytesN, String, Vec};
+
+use crate::{DefaultPolicy, JoinPolicy, LateFeeConfig, PayoutMode};
+
+/// The contract admin replaced the contract code.
+#[contractevent(topics = ["upgraded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// `migrate` rewrote every stored group; the schema is now at `version`.
+#[contractevent(topics = ["migrated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Migrated {
+    pub version: String,
+}
+
+/// The contract admin set or cleared the registry kept in sync.
+#[contractevent(topics = ["registry"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistrySet {
+    pub admin: Address,
+    pub registry: Option<Address>,
+}
+
+/// The contract admin set the fee applied to newly created groups.
+#[contractevent(topics = ["dflt_fee"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultFeeSet {
+    pub admin: Address,
+    pub fee_bps: u32,
+}
+
+/// The contract admin set the cap on every group's fee.
+#[contractevent(topics = ["max_fee"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxFeeSet {
+    pub admin: Address,
+    pub fee_bps: u32,
+}
+
+/// A member left a group's waitlist.
+#[contractevent(topics = ["wl_left"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WaitlistLeft {
+    pub group_id: String,
+    pub member: Address,
+}
+
+/// The head of the waitlist took over a defaulted member's seat.
+#[contractevent(topics = ["seat_fill"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatFilled {
+    pub group_id: String,
+    pub defaulter: Address,
+    pub member: Address,
+}
+
+/// A group admin set who admits members to a public group.
+#[contractevent(topics = ["join_pol"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinPolicySet {
+    pub group_id: String,
+    pub policy: JoinPolicy,
+}
+
+/// The admin or a member approved a join request.
+#[contractevent(topics = ["join_appr"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinApproved {
+    pub group_id: String,
+    pub applicant: Address,
+    pub approver: Address,
+}
+
+/// The admin rejected a join request.
+#[contractevent(topics = ["join_rej"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinRejected {
+    pub group_id: String,
+    pub applicant: Address,
+}
+
+/// An applicant withdrew their join request.
+#[contractevent(topics = ["join_wd"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinWithdrawn {
+    pub group_id: String,
+    pub member: Address,
+}
+
+/// A group admin added a member to the group's allowlist.
+#[contractevent(topics = ["invited"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Invited {
+    pub group_id: String,
+    pub member: Address,
+    pub expires_at: Option<u64>,
+}
+
+/// A group admin registered a one-time invite code by its hash.
+#[contractevent(topics = ["inv_code"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteCodeAdded {
+    pub group_id: String,
+    pub code_hash: BytesN<32>,
+    pub invitee: Address,
+    pub expires_at: Option<u64>,
+}
+
+/// A group admin revoked a member's allowlist entry.
+#[contractevent(topics = ["inv_rev"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteRevoked {
+    pub group_id: String,
+    pub member: Address,
+}
+
+/// A group admin revoked an unredeemed invite code.
+#[contractevent(topics = ["code_rev"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteCodeRevoked {
+    pub group_id: String,
+    pub code_hash: BytesN<32>,
+}
+
+/// `join_group` filed a join request instead of admitting the member.
+#[contractevent(topics = ["join_req"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinRequested {
+    pub group_id: String,
+    pub member: Address,
+}
+
+/// A member voted to cancel an Active or Paused group.
+#[contractevent(topics = ["cncl_vote"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelVoted {
+    pub group_id: String,
+    pub member: Address,
+    pub votes: u32,
+}
+
+/// A cancelled group's settlement returned escrow to a member.
+#[contractevent(topics = ["settle"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Settled {
+    pub group_id: String,
+    pub member: Address,
+    pub amount: i128,
+}
+
+/// A member proposed extending the current and later round deadlines.
+#[contractevent(topics = ["ext_prop"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionProposed {
+    pub group_id: String,
+    pub member: Address,
+    pub round: u32,
+    pub seconds: u64,
+}
+
+/// A member voted for the open deadline extension.
+#[contractevent(topics = ["ext_vote"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionVoted {
+    pub group_id: String,
+    pub member: Address,
+    pub votes: u32,
+}
+
+/// A member left an open group.
+#[contractevent(topics = ["left"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Left {
+    pub group_id: String,
+    pub member: Address,
+}
+
+/// A member handed their seat to a new address.
+#[contractevent(topics = ["seat_xfer"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatTransferred {
+    pub group_id: String,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// A group's platform fee was set.
+#[contractevent(topics = ["grp_fee"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupFeeSet {
+    pub group_id: String,
+    pub fee_bps: u32,
+}
+
+/// A group admin set the collateral each member escrows.
+#[contractevent(topics = ["coll_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralSet {
+    pub group_id: String,
+    pub collateral_amount: i128,
+}
+
+/// A group admin set how missed contributions are covered.
+#[contractevent(topics = ["dflt_pol"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultPolicySet {
+    pub group_id: String,
+    pub policy: DefaultPolicy,
+}
+
+/// A group admin set the share of each contribution kept in reserve.
+#[contractevent(topics = ["rsv_bps"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveBpsSet {
+    pub group_id: String,
+    pub reserve_bps: u32,
+}
+
+/// A group admin set or cleared the late fee.
+#[contractevent(topics = ["late_fee"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateFeeSet {
+    pub group_id: String,
+    pub config: Option<LateFeeConfig>,
+}
+
+/// A group admin set the grace period after each deadline.
+#[contractevent(topics = ["grace"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GracePeriodSet {
+    pub group_id: String,
+    pub grace_period: u64,
+}
+
+/// A group admin set how each round's recipient is picked.
+#[contractevent(topics = ["pay_mode"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutModeSet {
+    pub group_id: String,
+    pub mode: PayoutMode,
+}
+
+/// A group admin proposed a fixed payout order.
+#[contractevent(topics = ["ord_prop"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderProposed {
+    pub group_id: String,
+    pub order: Vec<u32>,
+}
+
+/// A member approved the proposed payout order.
+#[contractevent(topics = ["ord_ok"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderApproved {
+    pub group_id: String,
+    pub member: Address,
+    pub approvals: u32,
+}
+
+/// A member committed to a payout order seed.
+#[contractevent(topics = ["seed_cmt"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedCommitted {
+    pub group_id: String,
+    pub member: Address,
+    pub commitment: BytesN<32>,
+}
+
+/// A member revealed the secret behind their seed commitment.
+#[contractevent(topics = ["seed_rvl"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedRevealed {
+    pub group_id: String,
+    pub member: Address,
+    pub secret: BytesN<32>,
+}
+
+/// A member offered to swap payout positions.
+#[contractevent(topics = ["swap_prop"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapProposed {
+    pub group_id: String,
+    pub proposer: Address,
+    pub my_round: u32,
+    pub their_round: u32,
+    pub premium: i128,
+}
+
+/// Two members swapped payout positions.
+#[contractevent(topics = ["swapped"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Swapped {
+    pub group_id: String,
+    pub proposer: Address,
+    pub accepter: Address,
+    pub my_round: u32,
+    pub their_round: u32,
+    pub premium: i128,
+}
+
+/// A member withdrew their swap offer.
+#[contractevent(topics = ["swap_cxl"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapCancelled {
+    pub group_id: String,
+    pub proposer: Address,
+}
+
+/// A member committed a sealed bid.
+#[contractevent(topics = ["bid_cmt"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidCommitted {
+    pub group_id: String,
+    pub round: u32,
+    pub bidder: Address,
+}
+
+/// A group admin set who may close rounds and the keeper bounty.
+#[contractevent(topics = ["keepers"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperConfigSet {
+    pub group_id: String,
+    pub restricted: bool,
+    pub bounty_bps: u32,
+}
+
+/// A member claimed the refund of a contribution.
+#[contractevent(topics = ["refunded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Refunded {
+    pub group_id: String,
+    pub member: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+/// The seed reveal window opened.
+#[contractevent(topics = ["reveal"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevealOpened {
+    pub group_id: String,
+    pub reveal_deadline: u64,
+}
+
+/// A round's pot went to the winning bidder.
+#[contractevent(topics = ["auction"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionWon {
+    pub group_id: String,
+    pub round: u32,
+    pub winner: Address,
+    pub discount_bps: u32,
+    pub dividend: i128,
+}
+
+/// The keeper that closed a round was paid a bounty.
+#[contractevent(topics = ["bounty"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyPaid {
+    pub group_id: String,
+    pub keeper: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+/// A round's platform fee was sent to the group's treasury.
+#[contractevent(topics = ["fee"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeePaid {
+    pub group_id: String,
+    pub treasury: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+/// A member joined a group's waitlist.
+#[contractevent(topics = ["waitlist"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Waitlisted {
+    pub group_id: String,
+    pub member: Address,
+    pub position: u32,
+}
+
+/// The head of the waitlist was admitted to a freed seat.
+#[contractevent(topics = ["promoted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Promoted {
+    pub group_id: String,
+    pub member: Address,
+}
+
+/// A member escrowed collateral.
+#[contractevent(topics = ["collat"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralPosted {
+    pub group_id: String,
+    pub member: Address,
+    pub balance: i128,
+}
+
+/// A member's remaining collateral was released.
+#[contractevent(topics = ["coll_rel"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralReleased {
+    pub group_id: String,
+    pub member: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// A defaulter's collateral covered a missed contribution.
+#[contractevent(topics = ["slashed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralSlashed {
+    pub group_id: String,
+    pub member: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+/// The reserve covered a defaulter's share of a round.
+#[contractevent(topics = ["covered"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultCovered {
+    pub group_id: String,
+    pub member: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+/// A cured member's late contribution to a closed round was paid out.
+#[contractevent(topics = ["late_pay"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LatePaid {
+    pub group_id: String,
+    pub member: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+/// An extension passed and the round deadlines moved back.
+#[contractevent(topics = ["extended"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub group_id: String,
+    pub round: u32,
+    pub seconds: u64,
+}
+
+/// A group's remaining reserve was paid out.
+#[contractevent(topics = ["rsv_rel"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveReleased {
+    pub group_id: String,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// A member placed or revealed a bid.
+#[contractevent(topics = ["bid"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidPlaced {
+    pub group_id: String,
+    pub round: u32,
+    pub bidder: Address,
+    pub discount_bps: u32,
+}
+
+/// A group was cancelled, by its admin before it became active or by a
+/// member vote. Published as the positional `(caller, group_id)` tuple the
+/// original `cancelled` event used.
+#[contractevent(topics = ["cancelled"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cancelled {
+    pub caller: Address,
+    pub group_id: String,
+}
@@ -1,10 +1,12 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, String,
-    Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token,
+    Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
+mod events;
+
 // #696: Error codes are unique per-contract. Savings contract codes start at 1.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -46,20 +48,45 @@ pub enum Error {
     GroupIsPrivate = 34,
     GroupIdAlreadyExists = 35,
     StringTooLong = 36,
+    NativeTokenNotSet = 37,
+    InvalidConfig = 38,
+    NotKeeper = 39,
+    BiddingClosed = 40,
+    InvalidBid = 41,
+    BiddingOpen = 42,
+    RevealPending = 43,
+    InvalidSwap = 44,
+    NoProposal = 45,
+    InviteNotFound = 46,
+    NoJoinRequest = 47,
+    AdminCannotLeave = 48,
+    WaitlistEmpty = 49,
+    SeatAlreadyPaid = 50,
 }
 
-// #697: Contract version for schema migration tracking.
-pub const CONTRACT_VERSION: &str = "0.1.0";
+// #697: Contract version for schema migration tracking. The version whose
+// schema the stored records follow is kept under `DataKey::Version`; when it
+// differs from this constant after an upgrade, `migrate` rewrites old records.
+pub const CONTRACT_VERSION: &str = "0.3.0";
+// Deployments from before `DataKey::Version` existed.
+pub const LEGACY_CONTRACT_VERSION: &str = "0.1.0";
 
 pub const MIN_MEMBERS: u32 = 3;
 pub const MAX_MEMBERS: u32 = 20;
 pub const MIN_CONTRIBUTION: i128 = 10_000_000;
 pub const MAX_CONTRIBUTION: i128 = 1_000_000_000_000;
 pub const DEFAULT_PLATFORM_FEE_BPS: u32 = 200;
+pub const DEFAULT_MAX_PLATFORM_FEE_BPS: u32 = 1_000;
+pub const BPS_DENOMINATOR: u32 = 10_000;
 pub const MAX_START_TIMESTAMP_OFFSET: u64 = 31_536_000;
 pub const GROUP_TTL_EXTEND: u32 = 6_312_000;
 pub const PAGE_SIZE: u32 = 100;
-pub const GRACE_PERIOD_SECONDS: u64 = 259_200; // 3 days
+pub const GRACE_PERIOD_SECONDS: u64 = 259_200; // 3 days, the default per group
+pub const MAX_GRACE_PERIOD_SECONDS: u64 = 2_592_000; // 30 days
+pub const MAX_EXTENSION_SECONDS: u64 = 2_592_000; // 30 days per vote
+pub const MIN_ROUND_SECONDS: u64 = 3_600;
+pub const MAX_ROUND_SECONDS: u64 = 31_536_000;
+pub const MAX_WAITLIST: u32 = 10;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -68,6 +95,7 @@ pub enum GroupStatus {
     Active,
     Completed,
     Paused,
+    Cancelled,
 }
 
 #[contracttype]
@@ -85,14 +113,248 @@ pub enum MemberStatus {
 pub enum Frequency {
     Weekly,
     BiWeekly,
+    /// 30 days.
     Monthly,
+    Daily,
+    /// A fixed round length in seconds, between `MIN_ROUND_SECONDS` and
+    /// `MAX_ROUND_SECONDS`.
+    Custom(u64),
+    /// Rounds end on the start timestamp's day of month (clamped to the last
+    /// day of shorter months) and time of day, in UTC.
+    CalendarMonthly,
+}
+
+/// Who admits members to a public group; see `set_join_policy`. Private
+/// groups admit by invite instead.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JoinPolicy {
+    /// `join_group` admits the member immediately.
+    Open,
+    /// `join_group` files a `JoinRequest` that only the admin may approve.
+    AdminApproval,
+    /// `join_group` files a `JoinRequest` admitted by the admin, or once
+    /// this many members have approved it.
+    MemberQuorum(u32),
+}
+
+/// A pending request to join a group whose `JoinPolicy` requires approval.
+/// The group's collateral is escrowed when the request is filed and
+/// returned if it is rejected or withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinRequest {
+    pub requested_at: u64,
+    pub approvals: Vec<Address>,
+}
+
+/// A one-time invite code registered with `add_invite_code`. Only
+/// `invitee` can redeem it, so a code seen in a pending transaction cannot
+/// be used by anyone else.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CodeInvite {
+    pub invitee: Address,
+    pub expires_at: Option<u64>,
+}
+
+/// How a group picks each round's recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutMode {
+    /// Recipients follow the pseudorandom `payout_order`.
+    Shuffled,
+    /// Members who have not been paid bid for each round's pot; see `AuctionConfig`.
+    Auction(AuctionConfig),
+    /// Recipients follow an order proposed by the admin with
+    /// `propose_payout_order`. The group only activates once a majority of
+    /// its members has approved the order.
+    Fixed,
+    /// Recipients follow a `payout_order` shuffled with entropy every member
+    /// commits (`commit_seed`) while the group is Open and reveals
+    /// (`reveal_seed`) within this many seconds of the last commitment to
+    /// the full group; see `OrderTranscript`.
+    CommitReveal(u64),
+}
+
+/// How a round is paid out when a member has defaulted on it. Whatever the
+/// policy, the missing contribution is first slashed from the defaulter's
+/// collateral (see `set_collateral`) while it lasts; the policy decides what
+/// happens once there is none.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DefaultPolicy {
+    /// The round pays out only what was contributed.
+    ShrinkPot,
+    /// Only the defaulter's collateral covers the missing contribution; the
+    /// same as `ShrinkPot`.
+    Collateral,
+    /// The missing contribution is drawn from the group's reserve (see
+    /// `set_reserve_bps`) while it can cover it. A defaulter who cures
+    /// repays the reserve.
+    Reserve,
+    /// From the round after a default, the remaining members each pay their
+    /// pro-rata share of the defaulters' contributions on top of their own.
+    TopUp,
+}
+
+/// How a late fee is sized.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LateFee {
+    /// A fixed amount in the group's token.
+    Flat(i128),
+    /// Basis points of `contribution_amount`.
+    Bps(u32),
+}
+
+/// Who receives late fees.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LateFeeDestination {
+    /// The group's insurance reserve.
+    Reserve,
+    /// The group's treasury, counted in `get_fees_collected`.
+    Treasury,
+    /// The other non-defaulted members, in equal shares. The fee is rounded
+    /// down to a multiple of their number.
+    Members,
+}
+
+/// A fee charged on top of `contribution_amount` when a member contributes
+/// after the round deadline but within the grace period. When `escalating`,
+/// the fee is charged once per started day past the deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateFeeConfig {
+    pub fee: LateFee,
+    pub escalating: bool,
+    pub destination: LateFeeDestination,
+}
+
+/// Everything needed to recompute a `PayoutMode::CommitReveal` group's
+/// payout order off-chain.
+///
+/// `inputs[i]` is the secret revealed by `members[i]`, or that member's
+/// commitment when they did not reveal in time (`revealed[i]` is false).
+/// Every member is listed, in join order. `seed` is
+/// `sha256(group_id || inputs[0] || inputs[1] || ...)`, and the order is a
+/// Fisher-Yates shuffle of `0..total_members` in which, for `i` from
+/// `total_members - 1` down to 1, position `i` swaps with position
+/// `u64_be(sha256(seed || u32_be(i))[0..8]) % (i + 1)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderTranscript {
+    pub members: Vec<Address>,
+    pub inputs: Vec<BytesN<32>>,
+    pub revealed: Vec<bool>,
+    pub seed: BytesN<32>,
+}
+
+/// Bidding ROSCA settings. Each round, members who have not yet received a
+/// payout bid the share of the pot (in basis points) they are willing to give
+/// up. The highest bidder takes the pot minus that discount and the discount
+/// is shared equally among the other non-defaulted members. Ties go to the
+/// bid placed (or revealed) first; without a valid bid the round goes to the
+/// earliest unpaid member in `payout_order`.
+///
+/// Bids are accepted during the `bid_window` seconds before the round
+/// deadline. Sealed bids are committed as `sha256(discount_bps (big-endian
+/// u32) || salt)` in that window and revealed during the `bid_window`
+/// seconds after the deadline. The payout is only distributed once bidding
+/// (including reveals) has closed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionConfig {
+    pub sealed: bool,
+    pub bid_window: u64,
+}
+
+/// The leading bid for a round of an auction group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bid {
+    pub bidder: Address,
+    pub discount_bps: u32,
+}
+
+/// An open offer by `proposer` to trade their payout position (`my_round`)
+/// for `their_round`. `premium`, in the group's token, is held by the
+/// contract until the swap is accepted (paid to the counterparty) or
+/// withdrawn (returned to the proposer).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapOffer {
+    pub proposer: Address,
+    pub my_round: u32,
+    pub their_round: u32,
+    pub premium: i128,
+}
+
+/// A members' proposal to push the current round's deadline, and every
+/// later one, back by `seconds`. It passes once a strict majority of members
+/// has voted for it, and lapses when the round ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionProposal {
+    pub round: u32,
+    pub seconds: u64,
+    pub votes: Vec<Address>,
+}
+
+/// Settings `create_group` can apply up front instead of through the
+/// setters, which can still change them while the group is Open.
+/// `GroupOptions::default()` matches a group created without options.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupOptions {
+    /// SEP-41 token the group is denominated in; `None` for native XLM.
+    pub token_address: Option<Address>,
+    /// Platform fee in basis points; `None` for the contract's default fee.
+    /// See `set_group_fee`.
+    pub fee_bps: Option<u32>,
+    /// See `set_payout_mode`.
+    pub payout_mode: PayoutMode,
+    /// See `set_collateral`. The admin's own collateral is posted on creation.
+    pub collateral_amount: i128,
+    /// See `set_default_policy`.
+    pub default_policy: DefaultPolicy,
+    /// See `set_reserve_bps`.
+    pub reserve_bps: u32,
+    /// See `set_grace_period`.
+    pub grace_period: u64,
+    /// See `set_join_policy`.
+    pub join_policy: JoinPolicy,
+    /// See `set_late_fee`. A zero fee sets no late fee.
+    pub late_fee: LateFeeConfig,
+}
+
+impl Default for GroupOptions {
+    fn default() -> Self {
+        GroupOptions {
+            token_address: None,
+            fee_bps: None,
+            payout_mode: PayoutMode::Shuffled,
+            collateral_amount: 0,
+            default_policy: DefaultPolicy::ShrinkPot,
+            reserve_bps: 0,
+            grace_period: GRACE_PERIOD_SECONDS,
+            join_policy: JoinPolicy::Open,
+            late_fee: LateFeeConfig {
+                fee: LateFee::Flat(0),
+                escalating: false,
+                destination: LateFeeDestination::Treasury,
+            },
+        }
+    }
 }
 
 /// A savings group where members contribute a fixed amount each round and
 /// one member receives the full pooled amount (minus platform fee) per round.
 ///
 /// **Payout order**: When the group transitions to Active, the contract
-/// generates a pseudorandom payout order using the ledger's PRNG.  This
+/// generates a pseudorandom payout order using the ledger's PRNG unless the
+/// group's `PayoutMode` sets the order another way.  This
 /// replaces the previous deterministic join-order-based selection, which
 /// guaranteed the admin (always join_order 0) would receive the first
 /// payout — creating a rug-pull vector when combined with write-then-revert
@@ -100,6 +362,49 @@ pub enum Frequency {
 #[contracttype]
 #[derive(Clone)]
 pub struct SavingsGroup {
+    pub group_id: String,
+    pub admin: Address,
+    pub name: String,
+    pub contribution_amount: i128,
+    pub total_members: u32,
+    pub frequency: Frequency,
+    pub start_timestamp: u64,
+    pub status: GroupStatus,
+    pub is_public: bool,
+    pub current_round: u32,
+    pub platform_fee_percent: u32,
+    pub treasury: Address,
+    /// SEP-41 token the group is denominated in. `None` means native XLM,
+    /// which is moved through the Stellar Asset Contract configured at
+    /// `initialize`.
+    pub token_address: Option<Address>,
+    /// Payout order: payout_order[i] = index (join_order) of the member who
+    /// receives the payout in round i+1.  Fixed once when the group becomes
+    /// Active: the approved proposal for `PayoutMode::Fixed`, a shuffle of
+    /// the revealed seeds for `PayoutMode::CommitReveal`, and otherwise a
+    /// Fisher-Yates shuffle seeded by the ledger PRNG.
+    pub payout_order: Vec<u32>,
+    pub payout_mode: PayoutMode,
+    /// Collateral each member escrows on joining, in the group's token; `0`
+    /// for none. See `set_collateral`.
+    pub collateral_amount: i128,
+    pub default_policy: DefaultPolicy,
+    /// Share of each contribution (basis points) set aside in the group's
+    /// reserve rather than paid into the round's pot. See `set_reserve_bps`.
+    pub reserve_bps: u32,
+    /// Seconds after a round's deadline during which members may still
+    /// contribute. See `set_grace_period`.
+    pub grace_period: u64,
+    /// Invites (allowlist entries and invite codes) issued and not yet
+    /// redeemed or revoked. See `invite_member` and `add_invite_code`.
+    pub open_invites: u32,
+    pub join_policy: JoinPolicy,
+}
+
+/// `SavingsGroup` as stored before 0.3.0, read only by `migrate`.
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacySavingsGroup {
     pub group_id: String,
     pub admin: Address,
     pub name: String,
@@ -113,13 +418,37 @@ pub struct SavingsGroup {
     pub platform_fee_percent: u32,
     pub treasury: Address,
     pub token_address: Option<Address>,
-    /// Pseudorandom payout order: payout_order[i] = index (join_order) of
-    /// the member who receives the payout in round i+1.  Generated once
-    /// when the group becomes Active via Fisher-Yates shuffle seeded by
-    /// the ledger PRNG.
     pub payout_order: Vec<u32>,
 }
 
+impl LegacySavingsGroup {
+    fn upgrade(self) -> SavingsGroup {
+        SavingsGroup {
+            group_id: self.group_id,
+            admin: self.admin,
+            name: self.name,
+            contribution_amount: self.contribution_amount,
+            total_members: self.total_members,
+            frequency: self.frequency,
+            start_timestamp: self.start_timestamp,
+            status: self.status,
+            is_public: self.is_public,
+            current_round: self.current_round,
+            platform_fee_percent: self.platform_fee_percent,
+            treasury: self.treasury,
+            token_address: self.token_address,
+            payout_order: self.payout_order,
+            payout_mode: PayoutMode::Shuffled,
+            collateral_amount: 0,
+            default_policy: DefaultPolicy::ShrinkPot,
+            reserve_bps: 0,
+            grace_period: GRACE_PERIOD_SECONDS,
+            open_invites: 0,
+            join_policy: JoinPolicy::Open,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Member {
@@ -132,6 +461,35 @@ pub struct Member {
     pub payout_round: u32,
 }
 
+/// Who may trigger a group's time-based state transitions
+/// (`force_end_round`, `mark_defaulted`, `retry_distribution`), and what
+/// they are paid for doing so.
+///
+/// When `restricted` is false anyone may act as a keeper. Otherwise only the
+/// group admin, group members and the addresses in `keepers` may. A keeper
+/// whose call distributes a payout receives `bounty_bps` (basis points of the
+/// platform fee) out of that round's fee; the treasury receives the rest.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperConfig {
+    pub restricted: bool,
+    pub keepers: Vec<Address>,
+    pub bounty_bps: u32,
+}
+
+/// Snapshot taken when an Active or Paused group is cancelled. Every member
+/// is owed their net position (`total_contributed` minus what they received);
+/// if the escrow left in custody (`pot`) cannot cover the sum of positive net
+/// positions (`total_claims`), each member is repaid pro rata. Members are
+/// settled in batches starting at `next_index` in the `Members` list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Settlement {
+    pub pot: i128,
+    pub total_claims: i128,
+    pub next_index: u32,
+}
+
 // #643: Both per-round Vec<Contribution>/Vec<Payout> and per-member MemberStatus
 // track overlapping information about who paid/was paid each round. The per-round
 // vectors serve as an immutable audit trail (append-only, per-round granularity)
@@ -175,6 +533,41 @@ pub enum DataKey {
     LastGroupTimestamp(Address),
     Initialized,
     Admin,
+    NativeToken,
+    FeesCollected(String),
+    Refunded(String, u32, Address),
+    KeeperConfig(String),
+    Escrow(String),
+    CancelVotes(String),
+    Settlement(String),
+    DefaultFee,
+    MaxFee,
+    Version,
+    MigrationCursor,
+    Registry,
+    TopBid(String, u32),
+    BidCommit(String, u32, Address),
+    Dividends(String, Address),
+    ProposedOrder(String),
+    OrderApprovals(String),
+    SeedCommit(String, Address),
+    SeedReveal(String, Address),
+    RevealDeadline(String),
+    OrderTranscript(String),
+    SwapOffer(String, Address),
+    Collateral(String, Address),
+    Reserve(String),
+    ReservePaid(String, Address),
+    ReserveCover(String, u32, Address),
+    LateFee(String),
+    DeadlineShift(String),
+    PausedAt(String),
+    Extension(String),
+    Invite(String, Address),
+    InviteCode(String, BytesN<32>),
+    JoinRequest(String, Address),
+    JoinRequests(String),
+    Waitlist(String),
 }
 
 fn bump_group_keys(env: &Env, group_id: &String) {
@@ -196,20 +589,139 @@ fn bump_member_key(env: &Env, group_id: &String, member: &Address) {
     env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
 }
 
+/// Track the funds the contract holds on behalf of a group. Every transfer
+/// into or out of custody for the group's rounds goes through here so a
+/// cancelled group knows exactly what it can return.
+fn adjust_escrow(env: &Env, group_id: &String, delta: i128) -> Result<(), Error> {
+    let key = DataKey::Escrow(group_id.clone());
+    let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let balance = balance.checked_add(delta).ok_or(Error::ArithmeticOverflow)?;
+    env.storage().persistent().set(&key, &balance);
+    env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+    Ok(())
+}
+
+/// Resolve the token contract that holds a group's funds: the group's SEP-41
+/// token if set, otherwise the native XLM Stellar Asset Contract.
+fn group_token(env: &Env, group: &SavingsGroup) -> Result<Address, Error> {
+    match group.token_address.clone() {
+        Some(token) => Ok(token),
+        None => env
+            .storage()
+            .persistent()
+            .get(&DataKey::NativeToken)
+            .ok_or(Error::NativeTokenNotSet),
+    }
+}
+
+/// `timestamp` moved forward by `months` calendar months in UTC, keeping the
+/// time of day and the day of month, clamped to the last day of shorter months.
+fn add_months(timestamp: u64, months: u32) -> u64 {
+    let (year, month, day) = civil_from_days((timestamp / 86_400) as i64);
+    let total = year * 12 + (month as i64 - 1) + months as i64;
+    let (year, month) = (total.div_euclid(12), (total.rem_euclid(12) + 1) as u32);
+    let day = core::cmp::min(day, days_in_month(year, month));
+    days_from_civil(year, month, day) as u64 * 86_400 + timestamp % 86_400
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date (Howard Hinnant's
+/// `days_from_civil`).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of `days_from_civil`: the (year, month, day) of a day count.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// The privileged `GroupRegistry` entry points the savings contract calls to
+/// keep the registry's index in sync (#670). Each call is authorized by the
+/// savings contract's own address, passed as `contract_address`.
+#[contractclient(name = "RegistryClient")]
+pub trait RegistryInterface {
+    fn sync_member(
+        env: Env,
+        contract_address: Address,
+        group_id: String,
+        member: Address,
+        joined: bool,
+        member_count: u32,
+    );
+    fn sync_admin(env: Env, contract_address: Address, group_id: String, new_admin: Address);
+    fn sync_closed(env: Env, contract_address: Address, group_id: String, cancelled: bool);
+}
+
+/// Client for the configured registry, if any.
+fn registry_client(env: &Env) -> Option<RegistryClient<'_>> {
+    let registry: Address = env.storage().persistent().get(&DataKey::Registry)?;
+    Some(RegistryClient::new(env, &registry))
+}
+
+/// Push a join or removal to the registry, with the group's new member count.
+fn sync_registry_member(env: &Env, group_id: &String, member: &Address, joined: bool) {
+    if let Some(registry) = registry_client(env) {
+        let member_count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        registry.sync_member(&env.current_contract_address(), group_id, member, &joined, &member_count);
+    }
+}
+
+/// Tell the registry a group was cancelled (`cancelled = true`) or completed.
+fn sync_registry_closed(env: &Env, group_id: &String, cancelled: bool) {
+    if let Some(registry) = registry_client(env) {
+        registry.sync_closed(&env.current_contract_address(), group_id, &cancelled);
+    }
+}
+
 #[contract]
 pub struct SavingsContract;
 
 #[contractimpl]
 impl SavingsContract {
-    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+    /// Initializes the contract with its admin and the address of the native
+    /// XLM Stellar Asset Contract, which custodies funds for groups created
+    /// without a `token_address`.
+    ///
+    /// # Errors
+    /// - `Error::AlreadyInitialized` if the contract was already initialized.
+    pub fn initialize(env: Env, admin: Address, native_token: Address) -> Result<(), Error> {
         if env.storage().persistent().has(&DataKey::Initialized) {
             return Err(Error::AlreadyInitialized);
         }
         env.storage().persistent().set(&DataKey::Initialized, &true);
         env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage().persistent().set(&DataKey::NativeToken, &native_token);
 
         // #697: Emit contract version on initialization for schema migration tracking.
         let version = String::from_str(&env, CONTRACT_VERSION);
+        env.storage().persistent().set(&DataKey::Version, &version);
         env.events().publish(
             (symbol_short!("version"),),
             (version,),
@@ -218,6 +730,142 @@ impl SavingsContract {
         Ok(())
     }
 
+    /// Replaces the contract's code with an already-uploaded WASM blob.
+    ///
+    /// Storage is untouched; if the new code changes the record schema, the
+    /// admin follows up with `migrate` until it reports nothing left to do.
+    ///
+    /// # Errors
+    /// - `Error::NotAdmin` if the caller is not the contract admin.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_contract_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::Upgraded { new_wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Rewrites records stored by an older contract version, at most
+    /// `batch_size` groups per call so large deployments stay inside
+    /// Soroban's resource limits.
+    ///
+    /// # Errors
+    /// - `Error::NotAdmin` if the caller is not the contract admin.
+    ///
+    /// # Behavior
+    /// - Converts pre-0.3.0 group records, which have no `payout_mode`, to
+    ///   `PayoutMode::Shuffled`.
+    /// - Backfills `payout_order` for Active or Paused groups that still rely
+    ///   on the legacy join-order fallback, preserving the rounds already paid.
+    /// - Extends the TTL of every migrated group's records.
+    /// - Once every group is migrated, stores `CONTRACT_VERSION` as the schema
+    ///   version and publishes a `migrated` event.
+    /// - Returns the number of groups still to be migrated (0 when done or
+    ///   when storage is already on the current version).
+    pub fn migrate(env: Env, admin: Address, batch_size: u32) -> Result<u32, Error> {
+        Self::require_contract_admin(&env, &admin)?;
+
+        let current = String::from_str(&env, CONTRACT_VERSION);
+        if Self::schema_version(&env) == current {
+            return Ok(0);
+        }
+
+        let all_groups: Vec<String> = env
+            .storage().persistent().get(&DataKey::AllGroups)
+            .unwrap_or(Vec::new(&env));
+        let start: u32 = env
+            .storage().persistent().get(&DataKey::MigrationCursor)
+            .unwrap_or(0);
+        let end = core::cmp::min(start.saturating_add(batch_size), all_groups.len());
+
+        for i in start..end {
+            let group_id = all_groups.get(i).unwrap();
+            let raw: Option<Map<Symbol, Val>> =
+                env.storage().persistent().get(&DataKey::Group(group_id.clone()));
+            if let Some(raw) = raw {
+                // Pre-0.3.0 records have no `payout_mode` field.
+                let mut group = if raw.contains_key(Symbol::new(&env, "payout_mode")) {
+                    SavingsGroup::try_from_val(&env, &raw.to_val())
+                        .map_err(|_| Error::GroupNotFound)?
+                } else {
+                    LegacySavingsGroup::try_from_val(&env, &raw.to_val())
+                        .map_err(|_| Error::GroupNotFound)?
+                        .upgrade()
+                };
+                env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+                if group.payout_order.is_empty()
+                    && (group.status == GroupStatus::Active || group.status == GroupStatus::Paused)
+                {
+                    group.payout_order = Self::legacy_payout_order(&env, &group_id, &group)?;
+                    env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+                }
+                bump_group_keys(&env, &group_id);
+            }
+        }
+
+        if end < all_groups.len() {
+            env.storage().persistent().set(&DataKey::MigrationCursor, &end);
+            return Ok(all_groups.len() - end);
+        }
+
+        env.storage().persistent().remove(&DataKey::MigrationCursor);
+        env.storage().persistent().set(&DataKey::Version, &current);
+        events::Migrated { version: current }.publish(&env);
+        Ok(0)
+    }
+
+    /// Sets (or clears, with `None`) the `GroupRegistry` that joins, removals,
+    /// admin transfers, cancellations and completions are pushed to.
+    ///
+    /// # Errors
+    /// - `Error::NotAdmin` if the caller is not the contract admin.
+    pub fn set_registry(env: Env, admin: Address, registry: Option<Address>) -> Result<(), Error> {
+        Self::require_contract_admin(&env, &admin)?;
+
+        match registry.clone() {
+            Some(addr) => env.storage().persistent().set(&DataKey::Registry, &addr),
+            None => env.storage().persistent().remove(&DataKey::Registry),
+        }
+
+        events::RegistrySet { admin, registry }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets the platform fee (in basis points) applied to newly created groups.
+    ///
+    /// # Errors
+    /// - `Error::NotAdmin` if the caller is not the contract admin.
+    /// - `Error::InvalidConfig` if the fee exceeds the current maximum fee.
+    pub fn set_default_fee(env: Env, admin: Address, fee_bps: u32) -> Result<(), Error> {
+        Self::require_contract_admin(&env, &admin)?;
+
+        if fee_bps > Self::max_fee(&env) {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().persistent().set(&DataKey::DefaultFee, &fee_bps);
+
+        events::DefaultFeeSet { admin, fee_bps }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets the cap (in basis points) no group's platform fee may exceed.
+    ///
+    /// # Errors
+    /// - `Error::NotAdmin` if the caller is not the contract admin.
+    /// - `Error::InvalidConfig` if the cap exceeds 100% or is below the default fee.
+    pub fn set_max_fee(env: Env, admin: Address, fee_bps: u32) -> Result<(), Error> {
+        Self::require_contract_admin(&env, &admin)?;
+
+        if fee_bps > BPS_DENOMINATOR || fee_bps < Self::default_fee(&env) {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().persistent().set(&DataKey::MaxFee, &fee_bps);
+
+        events::MaxFeeSet { admin, fee_bps }.publish(&env);
+        Ok(())
+    }
+
     /// Creates a new savings group with the specified parameters.
     ///
     /// # Preconditions
@@ -228,6 +876,9 @@ impl SavingsContract {
     /// - `start_timestamp` must be in the future and within `MAX_START_TIMESTAMP_OFFSET` seconds.
     /// - Admin must not have created another group within the last 24 hours (rate limit).
     /// - `group_id` and `name` must not exceed 64 characters.
+    /// - Native XLM groups (no `GroupOptions::token_address`) require the
+    ///   contract to have been initialized with the native Stellar Asset Contract.
+    /// - If the options ask for collateral, the admin must be able to post it.
     ///
     /// # Errors
     /// - `Error::GroupIdAlreadyExists` if `group_id` is already taken.
@@ -236,10 +887,17 @@ impl SavingsContract {
     /// - `Error::ContributionTooLow` / `Error::ContributionTooHigh` if amount is out of bounds.
     /// - `Error::InvalidMemberCount` if `total_members` is outside the allowed range.
     /// - `Error::StartDateMustBeFuture` / `Error::StartDateTooFarInFuture` for invalid timestamps.
+    /// - `Error::NativeTokenNotSet` if the group is native XLM and no native token is configured.
+    /// - `Error::InvalidConfig` if a `Frequency::Custom` round length is out of
+    ///   bounds, or an option would be rejected by its setter.
     ///
     /// # Behavior
     /// - Initializes the group in `Open` status with zero members.
-    /// - Adds the admin as the first member.
+    /// - Applies `options`; `None` gives the default 3-day grace period, the
+    ///   contract's default platform fee and no collateral, reserve or late
+    ///   fee (see `GroupOptions::default`). The group admin can change them
+    ///   while the group is still Open.
+    /// - Adds the admin as the first member and escrows their collateral, if any.
     /// - Registers the group in the global groups list and the admin's user groups.
     /// - Publishes a `created` event.
     pub fn create_group(
@@ -253,7 +911,7 @@ impl SavingsContract {
         start_timestamp: u64,
         is_public: bool,
         treasury: Address,
-        token_address: Option<Address>,
+        options: Option<GroupOptions>,
     ) -> Result<SavingsGroup, Error> {
         admin.require_auth();
 
@@ -298,6 +956,24 @@ impl SavingsContract {
         if start_timestamp > env.ledger().timestamp() + MAX_START_TIMESTAMP_OFFSET {
             return Err(Error::StartDateTooFarInFuture);
         }
+        if let Frequency::Custom(seconds) = frequency {
+            if !(MIN_ROUND_SECONDS..=MAX_ROUND_SECONDS).contains(&seconds) {
+                return Err(Error::InvalidConfig);
+            }
+        }
+        let options = options.unwrap_or_default();
+        if options.token_address.is_none() && !env.storage().persistent().has(&DataKey::NativeToken) {
+            return Err(Error::NativeTokenNotSet);
+        }
+        let fee_bps = options.fee_bps.unwrap_or_else(|| Self::default_fee(&env));
+        if fee_bps > Self::max_fee(&env) || options.reserve_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidConfig);
+        }
+        Self::check_payout_mode(&options.payout_mode, options.grace_period)?;
+        Self::check_grace_period(&options.payout_mode, options.grace_period)?;
+        Self::check_collateral(options.collateral_amount, contribution_amount)?;
+        Self::check_join_policy(&options.join_policy, total_members)?;
+        Self::check_late_fee(&options.late_fee)?;
 
         let group = SavingsGroup {
             group_id: group_id.clone(),
@@ -310,14 +986,26 @@ impl SavingsContract {
             status: GroupStatus::Open,
             is_public,
             current_round: 0,
-            platform_fee_percent: DEFAULT_PLATFORM_FEE_BPS,
+            platform_fee_percent: fee_bps,
             treasury: treasury.clone(),
-            token_address,
+            token_address: options.token_address,
             payout_order: Vec::new(&env),
+            payout_mode: options.payout_mode,
+            collateral_amount: options.collateral_amount,
+            default_policy: options.default_policy,
+            reserve_bps: options.reserve_bps,
+            grace_period: options.grace_period,
+            open_invites: 0,
+            join_policy: options.join_policy,
         };
 
         env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
         env.storage().persistent().extend_ttl(&DataKey::Group(group_id.clone()), GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+        if !matches!(options.late_fee.fee, LateFee::Flat(0) | LateFee::Bps(0)) {
+            let key = DataKey::LateFee(group_id.clone());
+            env.storage().persistent().set(&key, &options.late_fee);
+            env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+        }
 
         let members: Vec<Address> = Vec::new(&env);
         env.storage().persistent().set(&DataKey::Members(group_id.clone()), &members);
@@ -341,6 +1029,7 @@ impl SavingsContract {
         env.storage().persistent().set(&DataKey::UserGroups(admin.clone()), &admin_groups);
 
         Self::add_admin_to_group(&env, admin.clone(), group_id.clone())?;
+        Self::post_collateral(&env, &group_id, &group, &admin, group.collateral_amount)?;
 
         env.events().publish(
             (symbol_short!("created"),),
@@ -356,23 +1045,37 @@ impl SavingsContract {
     /// - Caller must authorize the transaction.
     /// - The group must exist and be in `Open` status.
     /// - The group's start timestamp must not have passed yet.
-    /// - If the group is private, only the admin may join.
+    /// - If the group is private, only the admin and members invited with
+    ///   `invite_member` may join; see also `join_with_code`.
     /// - The member must not already be part of the group.
     /// - The group must not be full.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status.
+    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status
+    ///   and has no waitlist.
     /// - `Error::StartDateAlreadyPassed` if the current time is past the start timestamp.
-    /// - `Error::GroupIsPrivate` if a non-admin tries to join a private group.
-    /// - `Error::GroupIsFull` if the group has reached its maximum member count.
+    /// - `Error::GroupIsPrivate` if an uninvited non-admin tries to join a
+    ///   private group, or their invite has expired.
+    /// - `Error::GroupIsFull` if the group has reached its maximum member count
+    ///   and its waitlist, if any, holds `MAX_WAITLIST` addresses.
     /// - `Error::AlreadyMember` if the member is already part of the group.
     ///
     /// # Behavior
+    /// - A public group that admits without approval keeps a waitlist: when
+    ///   it is full, or already Active or Paused, the member's collateral is
+    ///   escrowed and they are queued instead; see `leave_waitlist`.
+    /// - Redeems the member's invite to a private group.
+    /// - If the public group's `JoinPolicy` requires approval, escrows the
+    ///   collateral and files a `JoinRequest` instead of admitting the member;
+    ///   see `approve_join`. Requesting twice is a no-op.
+    /// - Escrows the group's `collateral_amount` from the member, if any.
     /// - Adds the member to the group with `Active` status.
     /// - Increments the member count.
     /// - If the group reaches full capacity, transitions to `Active` status and initializes round 1.
+    ///   A `PayoutMode::Fixed` group waits until its payout order is approved.
     /// - Registers the group in the member's user groups list.
+    /// - Pushes the join to the registry, if one is configured.
     /// - Publishes a `joined` event.
     pub fn join_group(env: Env, member: Address, group_id: String) -> Result<(), Error> {
         member.require_auth();
@@ -383,6 +1086,10 @@ impl SavingsContract {
 
         bump_group_keys(&env, &group_id);
 
+        let running = group.status == GroupStatus::Active || group.status == GroupStatus::Paused;
+        if running && Self::has_waitlist(&group) {
+            return Self::enqueue_waitlist(&env, &group_id, &group, &member);
+        }
         if group.status != GroupStatus::Open {
             return Err(Error::GroupNotAcceptingMembers);
         }
@@ -390,977 +1097,4476 @@ impl SavingsContract {
             return Err(Error::StartDateAlreadyPassed);
         }
 
-        // #617: enforce private groups — only the admin may join a non-public group.
+        // #617: enforce private groups — only the admin and invited members
+        // may join a non-public group.
         if !group.is_public && member != group.admin {
-            return Err(Error::GroupIsPrivate);
-        }
-
-        let member_count: u32 = env
-            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
-            .unwrap_or(0);
-
-        if member_count >= group.total_members {
-            return Err(Error::GroupIsFull);
-        }
-
-        if env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
-            return Err(Error::AlreadyMember);
+            let key = DataKey::Invite(group_id.clone(), member.clone());
+            let expires_at: Option<u64> = env
+                .storage().persistent().get(&key)
+                .ok_or(Error::GroupIsPrivate)?;
+            if Self::invite_expired(&env, expires_at) {
+                return Err(Error::GroupIsPrivate);
+            }
+            env.storage().persistent().remove(&key);
+            Self::close_invite(&env, &group_id)?;
         }
 
-        let new_count = Self::add_member_to_group(&env, &member, &group_id);
-        bump_member_key(&env, &group_id, &member);
+        Self::admit_member(&env, &group_id, &group, &member)
+    }
 
-        let mut user_groups: Vec<String> = env
-            .storage().persistent().get(&DataKey::UserGroups(member.clone()))
-            .unwrap_or(Vec::new(&env));
-        user_groups.push_back(group_id.clone());
-        env.storage().persistent().set(&DataKey::UserGroups(member.clone()), &user_groups);
+    /// Leaves a group's waitlist and returns the escrowed collateral. Also
+    /// how queued addresses reclaim it once the group has completed or been
+    /// cancelled.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotMember` if the caller is not on the waitlist.
+    ///
+    /// # Behavior
+    /// - Publishes a `wl_left` event.
+    pub fn leave_waitlist(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
 
-        // TODO (#670): Add cross-contract call to registry contract
-        // When a registry contract address is configured, the savings contract should call:
-        //   registry::Client::new(&env, &registry_addr).add_member(&group_id, &member)
-        // This ensures the registry stays in sync with on-chain state automatically.
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
 
-        if new_count == group.total_members {
-            let mut group: SavingsGroup = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Group(group_id.clone()))
-                .ok_or(Error::GroupNotFound)?;
-            let mut group = group.clone();
+        let key = DataKey::Waitlist(group_id.clone());
+        let mut queue: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        let idx = queue.first_index_of(&member).ok_or(Error::NotMember)?;
+        queue.remove(idx);
+        env.storage().persistent().set(&key, &queue);
+        Self::release_collateral(&env, &group_id, &group, &member, &member)?;
 
-            // #744/#745: Generate pseudorandom payout order so the admin
-            // is NOT deterministically first. Uses Fisher-Yates shuffle
-            // seeded by the ledger's PRNG to produce an unbiased ordering.
-            let payout_order = Self::generate_payout_order(&env, group.total_members);
+        events::WaitlistLeft { group_id, member }.publish(&env);
+        Ok(())
+    }
 
-            group.status = GroupStatus::Active;
-            group.current_round = 1;
-            group.payout_order = payout_order;
+    /// Offers a defaulted member's seat to the head of the waitlist, who
+    /// takes it over by paying the seat's catch-up as `cure_default` would.
+    /// The admin and the waitlisted address must both authorize.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotActive` if the group is not Active.
+    /// - `Error::NotMember` if `defaulter` is not a member of the group.
+    /// - `Error::NotDefaulted` if `defaulter` has not defaulted.
+    /// - `Error::SeatAlreadyPaid` if `defaulter` has already received their
+    ///   payout, so the seat would owe every remaining round for nothing.
+    /// - `Error::WaitlistEmpty` if nobody is waiting.
+    ///
+    /// # Behavior
+    /// - Forfeits the defaulter's remaining collateral to the group's reserve
+    ///   and their reserve share to the other members.
+    /// - Moves the seat as `transfer_seat` does, then settles its catch-up
+    ///   from the new holder, whose escrowed collateral secures the seat.
+    ///   The new holder's `total_contributed` counts only what they pay.
+    /// - Publishes a `seat_fill` event, followed by `cured`.
+    pub fn fill_defaulted_seat(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        defaulter: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
 
-            env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        let group = Self::require_group_admin(&env, &group_id, &admin)?;
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+        let mut member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), defaulter.clone()))
+            .ok_or(Error::NotMember)?;
+        if member_data.status != MemberStatus::Defaulted {
+            return Err(Error::NotDefaulted);
+        }
+        if member_data.has_received_payout {
+            return Err(Error::SeatAlreadyPaid);
+        }
 
-            let deadline = Self::calculate_deadline(&env, &group, 1);
-            env.storage().persistent().set(&DataKey::RoundDeadline(group_id.clone(), 1), &deadline);
+        let key = DataKey::Waitlist(group_id.clone());
+        let mut queue: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        let next = queue.get(0).ok_or(Error::WaitlistEmpty)?;
+        next.require_auth();
+        queue.remove(0);
+        env.storage().persistent().set(&key, &queue);
+
+        let collateral_key = DataKey::Collateral(group_id.clone(), defaulter.clone());
+        let forfeited: i128 = env.storage().persistent().get(&collateral_key).unwrap_or(0);
+        if forfeited > 0 {
+            env.storage().persistent().remove(&collateral_key);
+            Self::credit_reserve(&env, &group_id, forfeited)?;
+            events::CollateralReleased {
+                group_id: group_id.clone(),
+                member: defaulter.clone(),
+                to: env.current_contract_address(),
+                amount: forfeited,
+            }
+            .publish(&env);
         }
+        env.storage().persistent().remove(&DataKey::ReservePaid(group_id.clone(), defaulter.clone()));
+        env.storage().persistent().remove(&DataKey::Dividends(group_id.clone(), defaulter.clone()));
+        member_data.total_contributed = 0;
+        Self::move_seat(&env, &group_id, &group, member_data, &defaulter, &next);
 
-        env.events().publish(
-            (symbol_short!("joined"),),
-            (member, new_count),
-        );
+        events::SeatFilled {
+            group_id: group_id.clone(),
+            defaulter,
+            member: next.clone(),
+        }
+        .publish(&env);
 
-        Ok(())
+        Self::catch_up(env, next, group_id, false)
     }
 
-    /// Cancel a group that is still open. Only the admin can cancel, and only
-    /// before the group becomes active (all members joined and rounds started).
-    /// Removes the group from global and per-user tracking, but does not delete
-    /// storage entries for the group itself (they will be garbage-collected by
-    /// the ledger).
-    pub fn cancel_group(env: Env, caller: Address, group_id: String) -> Result<(), Error> {
-        caller.require_auth();
-
-        let group: SavingsGroup = env
-            .storage()
+    /// Returns a group's waitlist, first in line first.
+    pub fn get_waitlist(env: Env, group_id: String) -> Vec<Address> {
+        env.storage()
             .persistent()
-            .get(&DataKey::Group(group_id.clone()))
-            .ok_or(Error::GroupNotFound)?;
+            .get(&DataKey::Waitlist(group_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        if caller != group.admin {
-            return Err(Error::NotAdmin);
-        }
+    /// Returns `member`'s 1-based position on a group's waitlist, if queued.
+    pub fn get_waitlist_position(env: Env, group_id: String, member: Address) -> Option<u32> {
+        Self::get_waitlist(env, group_id).first_index_of(&member).map(|i| i + 1)
+    }
+
+    /// Sets who admits members to an Open public group.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if a `MemberQuorum` is zero or not below
+    ///   `total_members`.
+    ///
+    /// # Behavior
+    /// - Requests already filed stay pending under the new policy.
+    /// - Publishes a `join_pol` event.
+    pub fn set_join_policy(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        policy: JoinPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
 
+        let mut group = Self::require_group_admin(&env, &group_id, &admin)?;
         if group.status != GroupStatus::Open {
             return Err(Error::GroupNotOpen);
         }
+        Self::check_join_policy(&policy, group.total_members)?;
 
-        // Remove from global groups list
-        let mut all_groups: Vec<String> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AllGroups)
-            .unwrap_or(Vec::new(&env));
-        let mut idx: u32 = 0;
-        while idx < all_groups.len() {
-            if all_groups.get(idx).unwrap() == group_id {
-                all_groups.remove(idx);
-                break;
-            }
-            idx += 1;
-        }
-        env.storage()
-            .persistent()
-            .set(&DataKey::AllGroups, &all_groups);
-
-        // Remove group from every member's UserGroups list
-        let members: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Members(group_id.clone()))
-            .unwrap_or(Vec::new(&env));
-
-        for member_addr in members.iter() {
-            let mut user_groups: Vec<String> = env
-                .storage()
-                .persistent()
-                .get(&DataKey::UserGroups(member_addr.clone()))
-                .unwrap_or(Vec::new(&env));
-            let mut i: u32 = 0;
-            while i < user_groups.len() {
-                if user_groups.get(i).unwrap() == group_id {
-                    user_groups.remove(i);
-                    break;
-                }
-                i += 1;
-            }
-            env.storage()
-                .persistent()
-                .set(&DataKey::UserGroups(member_addr), &user_groups);
-        }
-
-        env.events()
-            .publish((symbol_short!("cancelled"),), (caller, group_id));
+        group.join_policy = policy.clone();
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
 
+        events::JoinPolicySet { group_id, policy }.publish(&env);
         Ok(())
     }
 
-    /// Contributes the required amount for the current round of an active group.
-    ///
-    /// # Preconditions
-    /// - Caller must authorize the transaction.
-    /// - The group must exist and be in `Active` status.
-    /// - The caller must be a member of the group.
-    /// - The member must not have already paid for the current round.
-    /// - The member must not be in `Defaulted` status.
+    /// Approves `applicant`'s pending join request. The admin's approval
+    /// admits them at once; under `JoinPolicy::MemberQuorum` members may
+    /// approve too, and the quorum of member approvals admits them.
+    /// Approving twice is a no-op.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::GroupNotActive` if the group is not active.
-    /// - `Error::NotMember` if the caller is not a member of the group.
-    /// - `Error::MemberDefaulted` if the member has defaulted.
-    /// - `Error::AlreadyPaidThisRound` if the member already paid for this round.
-    /// - `Error::PaymentWindowClosed` if the current time is past the grace period after the deadline.
-    /// - `Error::ArithmeticOverflow` if the contribution amount causes an overflow.
+    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status.
+    /// - `Error::StartDateAlreadyPassed` once the start timestamp has passed;
+    ///   pending requests can then only be withdrawn or rejected.
+    /// - `Error::NoJoinRequest` if the applicant has no pending request.
+    /// - `Error::AdminOnly` if the approver is neither the admin nor, under a
+    ///   member quorum, a member.
+    /// - `Error::GroupIsFull` if the group filled up in the meantime.
     ///
     /// # Behavior
-    /// - Transfers tokens from the member to the contract if a token address is set.
-    /// - Records the contribution and updates the member's status to `PaidCurrentRound`.
-    /// - If the member pays after the deadline but within the grace period, status becomes `Overdue`.
-    /// - If the grace period has passed, the member is marked as `Defaulted`.
-    /// - If all members have paid, triggers payout distribution.
-    /// - Publishes a `contrib` event.
-    pub fn contribute(env: Env, member: Address, group_id: String) -> Result<(), Error> {
-        member.require_auth();
+    /// - Publishes a `join_appr` event, then admits the applicant as
+    ///   `join_group` does once approved.
+    pub fn approve_join(
+        env: Env,
+        approver: Address,
+        group_id: String,
+        applicant: Address,
+    ) -> Result<(), Error> {
+        approver.require_auth();
 
         let group: SavingsGroup = env
             .storage().persistent().get(&DataKey::Group(group_id.clone()))
             .ok_or(Error::GroupNotFound)?;
 
-        bump_group_keys(&env, &group_id);
-
-        if group.status != GroupStatus::Active {
-            return Err(Error::GroupNotActive);
-        }
-
-        let mut member_data: Member = env
-            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
-            .ok_or(Error::NotMember)?;
-
-        if member_data.status == MemberStatus::Defaulted {
-            return Err(Error::MemberDefaulted);
-        }
-        if member_data.status == MemberStatus::PaidCurrentRound {
-            return Err(Error::AlreadyPaidThisRound);
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotAcceptingMembers);
         }
-
-        let current_round = group.current_round;
-        let deadline: u64 = env
-            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), current_round))
-            .unwrap_or(0);
-
-        let deadline_with_grace = deadline
-            .checked_add(GRACE_PERIOD_SECONDS)
-            .ok_or(Error::Overflow)?;
-
-        // AUDIT FIX (#740, #741): The original code wrote MemberStatus::Defaulted to
-        // persistent storage and then returned Err(Error::PaymentWindowClosed). In
-        // Soroban, returning Err causes the host to revert *all* storage writes made
-        // within the same invocation, so that write was silently discarded and
-        // MemberStatus::Defaulted was never actually set in production. The orphaned
-        // write has been removed; late-contribution callers now get PaymentWindowClosed
-        // and the separate mark_defaulted() admin function is responsible for
-        // transitioning the member to Defaulted status.
-        if env.ledger().timestamp() > deadline_with_grace {
-            return Err(Error::PaymentWindowClosed);
+        if env.ledger().timestamp() >= group.start_timestamp {
+            return Err(Error::StartDateAlreadyPassed);
         }
+        let key = DataKey::JoinRequest(group_id.clone(), applicant.clone());
+        let mut request: JoinRequest = env
+            .storage().persistent().get(&key)
+            .ok_or(Error::NoJoinRequest)?;
+
+        let quorum = match group.join_policy {
+            JoinPolicy::MemberQuorum(quorum) => Some(quorum),
+            _ => None,
+        };
+        let approved = if approver == group.admin {
+            true
+        } else {
+            let quorum = quorum.ok_or(Error::AdminOnly)?;
+            if !env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), approver.clone())) {
+                return Err(Error::AdminOnly);
+            }
+            if request.approvals.contains(&approver) {
+                return Ok(());
+            }
+            request.approvals.push_back(approver.clone());
+            request.approvals.len() >= quorum
+        };
 
-        if env.ledger().timestamp() > deadline {
-            member_data.status = MemberStatus::Overdue;
-            env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
+        events::JoinApproved {
+            group_id: group_id.clone(),
+            applicant: applicant.clone(),
+            approver,
         }
+        .publish(&env);
 
-        // #606: move real funds from the member into the contract's custody
-        // when the group is denominated in a SEP-41 token.
-        if let Some(token) = group.token_address.clone() {
-            token::Client::new(&env, &token).transfer(
-                &member,
-                &env.current_contract_address(),
-                &group.contribution_amount,
-            );
+        if !approved {
+            env.storage().persistent().set(&key, &request);
+            return Ok(());
         }
+        Self::drop_join_request(&env, &group_id, &applicant);
+        Self::enroll_member(&env, &group_id, &applicant)
+    }
 
-        let contribution = Contribution {
-            member: member.clone(),
-            amount: group.contribution_amount,
-            round: current_round,
-            timestamp: env.ledger().timestamp(),
-        };
-
-        let mut round_contributions: Vec<Contribution> = env
-            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), current_round))
-            .unwrap_or(Vec::new(&env));
-        round_contributions.push_back(contribution);
-        env.storage().persistent().set(&DataKey::Contributions(group_id.clone(), current_round), &round_contributions);
-
-        member_data.status = MemberStatus::PaidCurrentRound;
-        member_data.total_contributed = member_data
-            .total_contributed
-            .checked_add(group.contribution_amount)
-            .ok_or(Error::ArithmeticOverflow)?;
-        env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
-        bump_member_key(&env, &group_id, &member);
-
-        env.events().publish(
-            (symbol_short!("contrib"),),
-            (group_id.clone(), member, group.contribution_amount, current_round),
-        );
+    /// Rejects `applicant`'s pending join request and returns their
+    /// escrowed collateral.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::NoJoinRequest` if the applicant has no pending request.
+    ///
+    /// # Behavior
+    /// - Publishes a `join_rej` event.
+    pub fn reject_join(env: Env, admin: Address, group_id: String, applicant: Address) -> Result<(), Error> {
+        admin.require_auth();
 
-        if Self::all_members_paid(&env, group_id.clone(), current_round) {
-            Self::distribute_payout(&env, group_id)?;
-        }
+        let group = Self::require_group_admin(&env, &group_id, &admin)?;
+        Self::close_join_request(&env, &group_id, &group, &applicant)?;
 
+        events::JoinRejected { group_id, applicant }.publish(&env);
         Ok(())
     }
 
-    /// Forcefully ends the current round when the group has stalled past the grace period.
-    ///
-    /// # Preconditions
-    /// - The group must exist and be in `Active` status.
-    /// - The current time must be past the deadline plus the grace period (3 days).
+    /// Withdraws the caller's pending join request, e.g. once it has expired
+    /// with the group's start, and returns their escrowed collateral.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::GroupNotActive` if the group is not active.
-    /// - `Error::RoundNotStalled` if the grace period has not yet elapsed.
+    /// - `Error::NoJoinRequest` if the caller has no pending request.
     ///
     /// # Behavior
-    /// - Marks all members with `Active` or `Overdue` status as `Defaulted`.
-    /// - Distributes the payout to the eligible recipient.
-    /// - Pauses the group after distributing the payout.
-    /// - Publishes a `paused` event.
-    pub fn force_end_round(env: Env, group_id: String) -> Result<(), Error> {
-        let mut group: SavingsGroup = env
+    /// - Publishes a `join_wd` event.
+    pub fn withdraw_join_request(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
             .storage().persistent().get(&DataKey::Group(group_id.clone()))
             .ok_or(Error::GroupNotFound)?;
+        Self::close_join_request(&env, &group_id, &group, &member)?;
 
-        if group.status != GroupStatus::Active {
-            return Err(Error::GroupNotActive);
-        }
-
-        let current_round = group.current_round;
-        let deadline: u64 = env
-            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), current_round))
-            .unwrap_or(0);
-
-        if env.ledger().timestamp() <= deadline + GRACE_PERIOD_SECONDS {
-            return Err(Error::RoundNotStalled);
-        }
-
-        let members: Vec<Address> = env
-            .storage().persistent().get(&DataKey::Members(group_id.clone()))
-            .unwrap_or(Vec::new(&env));
-
-        for member_addr in members.iter() {
-            let mut member_data: Member = env
-                .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
-                .unwrap();
-
-            if member_data.status == MemberStatus::Active
-                || member_data.status == MemberStatus::Overdue
-            {
-                member_data.status = MemberStatus::Defaulted;
-                env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member_addr), &member_data);
-            }
-        }
-
-        let _ = Self::distribute_payout(&env, group_id.clone());
-
-        group.status = GroupStatus::Paused;
-        if group.current_round >= group.total_members {
-            group.status = GroupStatus::Completed;
-        } else {
-            group.current_round += 1;
-            let deadline = Self::calculate_deadline(&env, &group, group.current_round);
-            env.storage().persistent().set(
-                &DataKey::RoundDeadline(group_id.clone(), group.current_round),
-                &deadline,
-            );
-        }
+        events::JoinWithdrawn { group_id, member }.publish(&env);
+        Ok(())
+    }
 
-        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+    /// Returns `member`'s pending join request, if any.
+    pub fn get_join_request(env: Env, group_id: String, member: Address) -> Option<JoinRequest> {
+        env.storage().persistent().get(&DataKey::JoinRequest(group_id, member))
+    }
 
-        env.events().publish((symbol_short!("paused"),), group_id);
-        Ok(())
+    /// Returns the applicants with a pending join request, oldest first.
+    pub fn get_join_requests(env: Env, group_id: String) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::JoinRequests(group_id))
+            .unwrap_or(Vec::new(&env))
     }
 
-    /// Pauses an active savings group.
-    ///
-    /// # Preconditions
-    /// - Caller must be the admin and authorize the transaction.
-    /// - The group must exist and be in `Active` status.
+    /// Joins an open group by revealing the preimage of an invite code
+    /// registered with `add_invite_code` for the caller. Each code admits one
+    /// member.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::AdminOnly` if the caller is not the group admin.
-    /// - `Error::GroupNotActive` if the group is not active.
+    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status.
+    /// - `Error::StartDateAlreadyPassed` if the current time is past the start timestamp.
+    /// - `Error::InviteNotFound` if `sha256(code)` is not an open code of the
+    ///   group, the code was issued to someone else, or it has expired.
+    /// - `Error::GroupIsFull` / `Error::AlreadyMember` as for `join_group`.
     ///
     /// # Behavior
-    /// - Sets the group status to `Paused`.
-    /// - Publishes a `paused` event.
-    pub fn pause_group(env: Env, admin: Address, group_id: String) -> Result<(), Error> {
-        admin.require_auth();
+    /// - Consumes the code, then proceeds as `join_group` does for an invited
+    ///   member: a `JoinPolicy` that requires approval files a `JoinRequest`,
+    ///   and a full group with a waitlist queues the member.
+    pub fn join_with_code(env: Env, member: Address, group_id: String, code: Bytes) -> Result<(), Error> {
+        member.require_auth();
 
-        let mut group: SavingsGroup = env
+        let group: SavingsGroup = env
             .storage().persistent().get(&DataKey::Group(group_id.clone()))
             .ok_or(Error::GroupNotFound)?;
 
-        if group.admin != admin {
-            return Err(Error::AdminOnly);
+        bump_group_keys(&env, &group_id);
+
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotAcceptingMembers);
         }
-        if group.status != GroupStatus::Active {
-            return Err(Error::GroupNotActive);
+        if env.ledger().timestamp() >= group.start_timestamp {
+            return Err(Error::StartDateAlreadyPassed);
         }
 
-        group.status = GroupStatus::Paused;
-        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+        let key = DataKey::InviteCode(group_id.clone(), code_hash);
+        let invite: CodeInvite = env
+            .storage().persistent().get(&key)
+            .ok_or(Error::InviteNotFound)?;
+        if invite.invitee != member || Self::invite_expired(&env, invite.expires_at) {
+            return Err(Error::InviteNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        Self::close_invite(&env, &group_id)?;
 
-        env.events().publish((symbol_short!("paused"),), group_id);
-        Ok(())
+        Self::admit_member(&env, &group_id, &group, &member)
     }
 
-    /// Resumes a paused savings group.
-    ///
-    /// # Preconditions
-    /// - Caller must be the admin and authorize the transaction.
-    /// - The group must exist and be in `Paused` status.
+    /// Adds `member` to a group's allowlist so they can `join_group` a
+    /// private group, optionally until `expires_at`. Re-inviting replaces the
+    /// expiry.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
     /// - `Error::AdminOnly` if the caller is not the group admin.
-    /// - `Error::GroupNotActive` if the group is not paused.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if `expires_at` is not in the future.
     ///
     /// # Behavior
-    /// - Sets the group status back to `Active`.
-    /// - Publishes a `resumed` event.
-    pub fn resume_group(env: Env, admin: Address, group_id: String) -> Result<(), Error> {
-        admin.require_auth();
-
-        let mut group: SavingsGroup = env
-            .storage().persistent().get(&DataKey::Group(group_id.clone()))
-            .ok_or(Error::GroupNotFound)?;
+    /// - Publishes an `invited` event.
+    pub fn invite_member(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        member: Address,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        Self::require_inviter(&env, &group_id, &admin, expires_at)?;
 
-        if group.admin != admin {
-            return Err(Error::AdminOnly);
+        let key = DataKey::Invite(group_id.clone(), member.clone());
+        if !env.storage().persistent().has(&key) {
+            Self::open_invite(&env, &group_id)?;
         }
-        if group.status != GroupStatus::Paused {
-            return Err(Error::GroupNotActive);
-        }
-
-        group.status = GroupStatus::Active;
-        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        env.storage().persistent().set(&key, &expires_at);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
 
-        env.events().publish((symbol_short!("resumed"),), group_id);
+        events::Invited { group_id, member, expires_at }.publish(&env);
         Ok(())
     }
 
-    /// Removes a member from an open savings group.
-    ///
-    /// # Preconditions
-    /// - Caller must be the admin and authorize the transaction.
-    /// - The group must exist and be in `Open` status.
-    /// - The member must be part of the group and have zero contributions.
+    /// Registers a one-time invite code for `invitee` by its SHA-256 hash,
+    /// optionally until `expires_at`. The invitee reveals the code to
+    /// `join_with_code`; re-registering a hash replaces its invitee and expiry.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
     /// - `Error::AdminOnly` if the caller is not the group admin.
-    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status.
-    /// - `Error::NotMember` if the specified member is not part of the group.
-    /// - `Error::MemberDefaulted` if the member has already made contributions.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if `expires_at` is not in the future.
     ///
     /// # Behavior
-    /// - Removes the member's data from storage.
-    /// - Removes the member from the group's members list.
-    /// - Decrements the member count.
-    /// - Publishes a `removed` event.
-    pub fn remove_member(
+    /// - Publishes an `inv_code` event.
+    pub fn add_invite_code(
         env: Env,
         admin: Address,
         group_id: String,
-        member: Address,
+        code_hash: BytesN<32>,
+        invitee: Address,
+        expires_at: Option<u64>,
     ) -> Result<(), Error> {
         admin.require_auth();
+        Self::require_inviter(&env, &group_id, &admin, expires_at)?;
 
-        let group: SavingsGroup = env
-            .storage().persistent().get(&DataKey::Group(group_id.clone()))
-            .ok_or(Error::GroupNotFound)?;
-
-        if group.admin != admin {
-            return Err(Error::AdminOnly);
-        }
-        if group.status != GroupStatus::Open {
-            return Err(Error::GroupNotAcceptingMembers);
-        }
-
-        let mut member_data: Member = env
-            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
-            .ok_or(Error::NotMember)?;
-
-        if member_data.total_contributed > 0 {
-            return Err(Error::MemberDefaulted);
-        }
-
-        env.storage().persistent().remove(&DataKey::MemberData(group_id.clone(), member.clone()));
-
-        let members: Vec<Address> = env
-            .storage().persistent().get(&DataKey::Members(group_id.clone()))
-            .unwrap_or(Vec::new(&env));
-
-        let mut new_members: Vec<Address> = Vec::new(&env);
-        for m in members.iter() {
-            if m != member {
-                new_members.push_back(m);
-            }
-        }
-        env.storage().persistent().set(&DataKey::Members(group_id.clone()), &new_members);
-
-        let count: u32 = env
-            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
-            .unwrap_or(0);
-        if count > 0 {
-            env.storage().persistent().set(&DataKey::MemberCount(group_id.clone()), &(count - 1));
+        let key = DataKey::InviteCode(group_id.clone(), code_hash.clone());
+        if !env.storage().persistent().has(&key) {
+            Self::open_invite(&env, &group_id)?;
         }
+        let invite = CodeInvite { invitee: invitee.clone(), expires_at };
+        env.storage().persistent().set(&key, &invite);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
 
-        env.events().publish((symbol_short!("removed"),), (group_id, member));
+        events::InviteCodeAdded { group_id, code_hash, invitee, expires_at }.publish(&env);
         Ok(())
     }
 
-    /// Transfers admin rights of a savings group to a new address.
-    ///
-    /// # Preconditions
-    /// - Caller must be the current admin and authorize the transaction.
-    /// - The group must exist.
+    /// Revokes `member`'s allowlist entry.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::AdminOnly` if the caller is not the current group admin.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::InviteNotFound` if the member has no open invite.
     ///
     /// # Behavior
-    /// - Updates the group's admin to the new address.
-    /// - Adds the group to the new admin's user groups list.
-    /// - Publishes an `adm_xfer` event.
-    pub fn transfer_admin(
-        env: Env,
-        group_id: String,
-        current_admin: Address,
-        new_admin: Address,
-    ) -> Result<(), Error> {
-        current_admin.require_auth();
-
-        let mut group: SavingsGroup = env
-            .storage().persistent().get(&DataKey::Group(group_id.clone()))
-            .ok_or(Error::GroupNotFound)?;
+    /// - Publishes an `inv_rev` event.
+    pub fn revoke_invite(env: Env, admin: Address, group_id: String, member: Address) -> Result<(), Error> {
+        admin.require_auth();
+        Self::require_group_admin(&env, &group_id, &admin)?;
 
-        if group.admin != current_admin {
-            return Err(Error::AdminOnly);
+        let key = DataKey::Invite(group_id.clone(), member.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::InviteNotFound);
         }
+        env.storage().persistent().remove(&key);
+        Self::close_invite(&env, &group_id)?;
 
-        group.admin = new_admin.clone();
-        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
-
-        let mut new_admin_groups: Vec<String> = env
-            .storage().persistent().get(&DataKey::UserGroups(new_admin.clone()))
-            .unwrap_or(Vec::new(&env));
-        new_admin_groups.push_back(group_id.clone());
-        env.storage().persistent().set(&DataKey::UserGroups(new_admin.clone()), &new_admin_groups);
-
-        env.events().publish(
-            (symbol_short!("adm_xfer"),),
-            (group_id, current_admin, new_admin),
-        );
+        events::InviteRevoked { group_id, member }.publish(&env);
         Ok(())
     }
 
-    /// Allows a defaulted member to pay catch-up contributions and return to active status.
-    ///
-    /// # Preconditions
-    /// - Caller must authorize the transaction.
-    /// - The group must exist and be in `Active` status.
-    /// - The caller must be a member of the group with `Defaulted` status.
+    /// Revokes an unredeemed invite code.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::GroupNotActive` if the group is not active.
-    /// - `Error::NotMember` if the caller is not a member of the group.
-    /// - `Error::NotDefaulted` if the member is not in `Defaulted` status.
-    /// - `Error::CatchUpRequired` if there are no missed rounds to catch up on.
-    /// - `Error::ArithmeticOverflow` if the catch-up amount causes an overflow.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::InviteNotFound` if no such code is open.
     ///
     /// # Behavior
-    /// - Calculates the number of missed rounds since the last paid round.
-    /// - Computes the catch-up amount as `contribution_amount * missed_rounds`.
-    /// - Updates the member's total contributions and sets status back to `Active`.
-    /// - Publishes a `cured` event with the catch-up amount and missed rounds.
-    pub fn cure_default(
+    /// - Publishes a `code_rev` event.
+    pub fn revoke_invite_code(
         env: Env,
-        member: Address,
+        admin: Address,
         group_id: String,
+        code_hash: BytesN<32>,
     ) -> Result<(), Error> {
-        member.require_auth();
+        admin.require_auth();
+        Self::require_group_admin(&env, &group_id, &admin)?;
+
+        let key = DataKey::InviteCode(group_id.clone(), code_hash.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::InviteNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        Self::close_invite(&env, &group_id)?;
+
+        events::InviteCodeRevoked { group_id, code_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Returns `member`'s invite expiry: `None` if they have no open invite,
+    /// `Some(None)` for an invite without expiry.
+    pub fn get_invite(env: Env, group_id: String, member: Address) -> Option<Option<u64>> {
+        env.storage().persistent().get(&DataKey::Invite(group_id, member))
+    }
+
+    /// Admit `member` to an open group once any invite has been redeemed:
+    /// file a join request when the group's `JoinPolicy` requires approval,
+    /// queue them when the group is full and keeps a waitlist, and otherwise
+    /// enroll them.
+    fn admit_member(env: &Env, group_id: &String, group: &SavingsGroup, member: &Address) -> Result<(), Error> {
+        if group.is_public && group.join_policy != JoinPolicy::Open && *member != group.admin {
+            return Self::file_join_request(env, group_id, group, member);
+        }
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count >= group.total_members && Self::has_waitlist(group) {
+            return Self::enqueue_waitlist(env, group_id, group, member);
+        }
+
+        Self::enroll_member(env, group_id, member)
+    }
 
+    /// Admit `member` to an open group once any invite has been checked:
+    /// capacity and membership checks, collateral, indexing and activation.
+    fn enroll_member(env: &Env, group_id: &String, member: &Address) -> Result<(), Error> {
         let group: SavingsGroup = env
             .storage().persistent().get(&DataKey::Group(group_id.clone()))
             .ok_or(Error::GroupNotFound)?;
 
-        if group.status != GroupStatus::Active {
-            return Err(Error::GroupNotActive);
-        }
-
-        let mut member_data: Member = env
-            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
-            .ok_or(Error::NotMember)?;
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
 
-        if member_data.status != MemberStatus::Defaulted {
-            return Err(Error::NotDefaulted);
+        if member_count >= group.total_members {
+            return Err(Error::GroupIsFull);
         }
 
-        let current_round = group.current_round;
-        let last_paid_round = member_data.payout_round;
+        if env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::AlreadyMember);
+        }
 
-        let missed_rounds = if current_round > last_paid_round + 1 {
-            current_round - last_paid_round - 1
-        } else {
-            0
-        };
+        // An approved applicant escrowed their collateral with the request.
+        let posted: i128 = env
+            .storage().persistent().get(&DataKey::Collateral(group_id.clone(), member.clone()))
+            .unwrap_or(0);
+        Self::post_collateral(env, group_id, &group, member, group.collateral_amount - posted)?;
 
-        if missed_rounds == 0 {
-            return Err(Error::CatchUpRequired);
-        }
+        let new_count = Self::add_member_to_group(env, member, group_id);
+        bump_member_key(env, group_id, member);
 
-        let catch_up_amount = group
-            .contribution_amount
-            .checked_mul(missed_rounds as i128)
-            .ok_or(Error::ArithmeticOverflow)?;
+        let mut user_groups: Vec<String> = env
+            .storage().persistent().get(&DataKey::UserGroups(member.clone()))
+            .unwrap_or(Vec::new(env));
+        user_groups.push_back(group_id.clone());
+        env.storage().persistent().set(&DataKey::UserGroups(member.clone()), &user_groups);
 
-        member_data.total_contributed = member_data
-            .total_contributed
-            .checked_add(catch_up_amount)
-            .ok_or(Error::ArithmeticOverflow)?;
-        member_data.status = MemberStatus::Active;
+        // #670: keep the registry's index in sync when one is configured.
+        sync_registry_member(env, group_id, member, true);
 
-        env.storage().persistent().set(
-            &DataKey::MemberData(group_id.clone(), member.clone()),
-            &member_data,
-        );
-        bump_member_key(&env, &group_id, &member);
+        if new_count == group.total_members {
+            Self::try_activate(env, group_id)?;
+        }
 
         env.events().publish(
-            (symbol_short!("cured"),),
-            (member, group_id, catch_up_amount, missed_rounds),
+            (symbol_short!("joined"),),
+            (member.clone(), new_count),
         );
 
         Ok(())
     }
 
-    /// Retries payout distribution for the current round if all members have paid.
-    ///
-    /// # Preconditions
-    /// - The group must exist and be in `Active` status.
-    /// - All members must have contributed for the current round.
-    ///
-    /// # Errors
-    /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::GroupNotActive` if the group is not active.
-    /// - `Error::NotAllPaid` if not all members have contributed.
-    ///
-    /// # Behavior
-    /// - If payouts have already been distributed for the current round, returns early.
-    /// - Otherwise, triggers the payout distribution for the current round.
-    pub fn retry_distribution(env: Env, group_id: String) -> Result<(), Error> {
-        let group: SavingsGroup = env
-            .storage().persistent().get(&DataKey::Group(group_id.clone()))
-            .ok_or(Error::GroupNotFound)?;
-
-        if group.status != GroupStatus::Active {
-            return Err(Error::GroupNotActive);
+    /// Escrow the applicant's collateral and queue their join request.
+    fn file_join_request(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        member: &Address,
+    ) -> Result<(), Error> {
+        let key = DataKey::JoinRequest(group_id.clone(), member.clone());
+        if env.storage().persistent().has(&key) {
+            return Ok(());
+        }
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count >= group.total_members {
+            return Err(Error::GroupIsFull);
+        }
+        if env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::AlreadyMember);
         }
 
-        let current_round = group.current_round;
-        let members: Vec<Address> = env
-            .storage().persistent().get(&DataKey::Members(group_id.clone()))
-            .unwrap_or(Vec::new(&env));
+        Self::post_collateral(env, group_id, group, member, group.collateral_amount)?;
 
-        let contributions: Vec<Contribution> = env
-            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), current_round))
-            .unwrap_or(Vec::new(&env));
+        let request = JoinRequest { requested_at: env.ledger().timestamp(), approvals: Vec::new(env) };
+        env.storage().persistent().set(&key, &request);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
 
-        if contributions.len() != members.len() {
-            return Err(Error::NotAllPaid);
-        }
+        let list_key = DataKey::JoinRequests(group_id.clone());
+        let mut applicants: Vec<Address> = env.storage().persistent().get(&list_key).unwrap_or(Vec::new(env));
+        applicants.push_back(member.clone());
+        env.storage().persistent().set(&list_key, &applicants);
+        env.storage().persistent().extend_ttl(&list_key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
 
-        let existing_payouts: Vec<Payout> = env
-            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), current_round))
-            .unwrap_or(Vec::new(&env));
+        events::JoinRequested { group_id: group_id.clone(), member: member.clone() }.publish(env);
+        Ok(())
+    }
 
-        if !existing_payouts.is_empty() {
-            return Ok(());
+    /// Drop a pending join request without admitting the applicant and
+    /// return their escrowed collateral.
+    fn close_join_request(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        applicant: &Address,
+    ) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::JoinRequest(group_id.clone(), applicant.clone())) {
+            return Err(Error::NoJoinRequest);
         }
-
-        Self::distribute_payout(&env, group_id)
+        Self::drop_join_request(env, group_id, applicant);
+        Self::release_collateral(env, group_id, group, applicant, applicant)
     }
 
-    /// Claims a refund for a member who contributed but did not receive a payout in a round.
-    ///
-    /// # Preconditions
-    /// - Caller must authorize the transaction.
-    /// - The group and member must exist.
-    /// - The member must have contributed for the specified round.
-    /// - The member must not have received a payout for that round.
-    ///
-    /// # Errors
-    /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::NotMember` if the caller is not a member of the group.
-    /// - `Error::NoRefundAvailable` if the member did not contribute or already received a payout.
-    ///
-    /// # Behavior
-    /// - Returns the contributed amount if the member is eligible for a refund.
-    pub fn claim_refund(
-        env: Env,
-        member: Address,
-        group_id: String,
-        round: u32,
-    ) -> Result<i128, Error> {
-        member.require_auth();
+    fn drop_join_request(env: &Env, group_id: &String, applicant: &Address) {
+        env.storage().persistent().remove(&DataKey::JoinRequest(group_id.clone(), applicant.clone()));
 
-        let _group: SavingsGroup = env
-            .storage().persistent().get(&DataKey::Group(group_id.clone()))
-            .ok_or(Error::GroupNotFound)?;
+        let list_key = DataKey::JoinRequests(group_id.clone());
+        let mut applicants: Vec<Address> = env.storage().persistent().get(&list_key).unwrap_or(Vec::new(env));
+        if let Some(i) = applicants.first_index_of(applicant) {
+            applicants.remove(i);
+            env.storage().persistent().set(&list_key, &applicants);
+        }
+    }
 
-        let member_data: Member = env
-            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
-            .ok_or(Error::NotMember)?;
+    /// Check the caller may issue invites for an Open group, with an expiry
+    /// that is either absent or in the future.
+    fn require_inviter(
+        env: &Env,
+        group_id: &String,
+        admin: &Address,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
+        let group = Self::require_group_admin(env, group_id, admin)?;
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        if expires_at.is_some_and(|t| t <= env.ledger().timestamp()) {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
 
-        let contributions: Vec<Contribution> = env
-            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), round))
-            .unwrap_or(Vec::new(&env));
+    fn require_group_admin(env: &Env, group_id: &String, admin: &Address) -> Result<SavingsGroup, Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        if group.admin != *admin {
+            return Err(Error::AdminOnly);
+        }
+        Ok(group)
+    }
 
-        let mut contributed_amount: i128 = 0;
-        let mut found = false;
-        for contrib in contributions.iter() {
-            if contrib.member == member {
-                contributed_amount = contrib.amount;
-                found = true;
-                break;
-            }
+    fn invite_expired(env: &Env, expires_at: Option<u64>) -> bool {
+        expires_at.is_some_and(|t| env.ledger().timestamp() >= t)
+    }
+
+    fn open_invite(env: &Env, group_id: &String) -> Result<(), Error> {
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        group.open_invites = group.open_invites.checked_add(1).ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        Ok(())
+    }
+
+    fn close_invite(env: &Env, group_id: &String) -> Result<(), Error> {
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        group.open_invites = group.open_invites.saturating_sub(1);
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        Ok(())
+    }
+
+    /// Cancel a group that is still open. Only the admin can cancel, and only
+    /// before the group becomes active (all members joined and rounds started);
+    /// Active or Paused groups are cancelled by member vote via `vote_cancel`.
+    ///
+    /// Sets the group to `Cancelled`, returns every member's collateral and
+    /// removes the group from global and per-user tracking. The group, member
+    /// and contribution records are kept so the cancellation and any
+    /// settlement remain auditable.
+    pub fn cancel_group(env: Env, caller: Address, group_id: String) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if caller != group.admin {
+            return Err(Error::NotAdmin);
         }
 
-        if !found || contributed_amount == 0 {
-            return Err(Error::NoRefundAvailable);
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
         }
 
-        let payouts: Vec<Payout> = env
-            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), round))
+        group.status = GroupStatus::Cancelled;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
             .unwrap_or(Vec::new(&env));
-
-        for payout in payouts.iter() {
-            if payout.recipient == member {
-                return Err(Error::NoRefundAvailable);
-            }
+        for member_addr in members.iter() {
+            Self::release_collateral(&env, &group_id, &group, &member_addr, &member_addr)?;
         }
+        Self::unindex_group(&env, &group_id);
+        sync_registry_closed(&env, &group_id, true);
 
-        Ok(contributed_amount)
+        events::Cancelled { caller, group_id }.publish(&env);
+
+        Ok(())
     }
 
-    /// Marks a member as defaulted if the grace period for the current round has elapsed.
+    /// Votes to cancel an Active or Paused group.
     ///
     /// # Preconditions
-    /// - The group must exist and be in `Active` status.
-    /// - The member must be part of the group.
-    /// - The current time must be past the deadline plus the grace period.
+    /// - Caller must be a member of the group and authorize the transaction.
+    /// - The group must be in `Active` or `Paused` status.
     ///
     /// # Errors
     /// - `Error::GroupNotFound` if the group does not exist.
-    /// - `Error::NotMember` if the member is not part of the group.
-    /// - `Error::GroupNotActive` if the group is not active.
+    /// - `Error::GroupNotActive` if the group is neither Active nor Paused.
+    /// - `Error::NotMember` if the caller is not a member of the group.
     ///
     /// # Behavior
-    /// - If the member is already defaulted or has received a payout, returns early.
-    /// - Sets the member's status to `Defaulted` if the grace period has elapsed.
-    /// - Publishes a `defaulted` event with the member and current round.
-    pub fn mark_defaulted(env: Env, member: Address, group_id: String) -> Result<(), Error> {
-        let group: SavingsGroup = env
+    /// - Records the vote; voting twice is a no-op.
+    /// - Once a strict majority of members has voted, the group becomes
+    ///   `Cancelled`, is removed from global and per-user tracking, and a
+    ///   `Settlement` snapshot is taken for `settle_cancelled`. The group's
+    ///   reserve is returned through the settlement along with the escrow.
+    /// - Publishes a `cncl_vote` event, and a `cancelled` event when the vote passes.
+    pub fn vote_cancel(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
+        let mut group: SavingsGroup = env
             .storage().persistent().get(&DataKey::Group(group_id.clone()))
             .ok_or(Error::GroupNotFound)?;
 
-        let mut member_data: Member = env
-            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
-            .ok_or(Error::NotMember)?;
-
-        if group.status != GroupStatus::Active {
+        if group.status != GroupStatus::Active && group.status != GroupStatus::Paused {
             return Err(Error::GroupNotActive);
         }
+        if !env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::NotMember);
+        }
 
-        if member_data.status == MemberStatus::Defaulted
-            || member_data.status == MemberStatus::ReceivedPayout
-        {
+        let mut votes: Vec<Address> = env
+            .storage().persistent().get(&DataKey::CancelVotes(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        if votes.contains(&member) {
             return Ok(());
         }
+        votes.push_back(member.clone());
+        env.storage().persistent().set(&DataKey::CancelVotes(group_id.clone()), &votes);
 
-        let deadline: u64 = env
-            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), group.current_round))
-            .unwrap_or(0);
+        events::CancelVoted {
+            group_id: group_id.clone(),
+            member: member.clone(),
+            votes: votes.len(),
+        }
+        .publish(&env);
 
-        if env.ledger().timestamp() > deadline + GRACE_PERIOD_SECONDS {
-            member_data.status = MemberStatus::Defaulted;
-            env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        if votes.len() * 2 <= members.len() {
+            return Ok(());
+        }
 
-            env.events().publish(
-                (symbol_short!("defaulted"),),
-                (member, group.current_round),
-            );
+        // Reserve shares count towards total_contributed, so the reserve is
+        // settled with the escrow.
+        let reserve: i128 = env
+            .storage().persistent().get(&DataKey::Reserve(group_id.clone()))
+            .unwrap_or(0);
+        if reserve > 0 {
+            env.storage().persistent().remove(&DataKey::Reserve(group_id.clone()));
+            adjust_escrow(&env, &group_id, reserve)?;
+        }
+
+        let mut total_claims: i128 = 0;
+        for member_addr in members.iter() {
+            total_claims = total_claims
+                .checked_add(Self::settlement_claim(&env, &group_id, &member_addr)?)
+                .ok_or(Error::ArithmeticOverflow)?;
         }
+        let settlement = Settlement {
+            pot: env.storage().persistent().get(&DataKey::Escrow(group_id.clone())).unwrap_or(0),
+            total_claims,
+            next_index: 0,
+        };
+        env.storage().persistent().set(&DataKey::Settlement(group_id.clone()), &settlement);
+        env.storage().persistent().extend_ttl(
+            &DataKey::Settlement(group_id.clone()),
+            GROUP_TTL_EXTEND,
+            GROUP_TTL_EXTEND,
+        );
+
+        group.status = GroupStatus::Cancelled;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        Self::unindex_group(&env, &group_id);
+        sync_registry_closed(&env, &group_id, true);
+
+        events::Cancelled { caller: member, group_id }.publish(&env);
 
         Ok(())
     }
 
-    // ─── Internal helpers ────────────────────────────────────────────
+    /// Returns escrowed funds of a cancelled group to its members, settling
+    /// at most `batch_size` members per call so a 20-member group fits in
+    /// Soroban's per-transaction resource limits.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction and be allowed to act as a keeper.
+    /// - The group must have been cancelled by member vote.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotKeeper` if the group restricts keepers and the caller is not one.
+    /// - `Error::NoRefundAvailable` if the group has no pending settlement.
+    ///
+    /// # Behavior
+    /// - Pays each member in the batch their net position, scaled down pro
+    ///   rata when the escrow cannot cover every claim, and returns their
+    ///   collateral in full.
+    /// - Publishes a `settle` event per member paid.
+    /// - Returns the number of members still to be settled.
+    pub fn settle_cancelled(
+        env: Env,
+        caller: Address,
+        group_id: String,
+        batch_size: u32,
+    ) -> Result<u32, Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
 
-    /// #744/#745: Generate a pseudorandom payout order using Fisher-Yates
-    /// shuffle seeded by the ledger's PRNG.  This ensures no member
-    /// (including the admin) is deterministically first in the payout
-    /// sequence, eliminating the admin-first rug-pull vector.
-    fn generate_payout_order(env: &Env, total_members: u32) -> Vec<u32> {
-        let mut order: Vec<u32> = Vec::new(env);
-        for i in 0..total_members {
-            order.push_back(i);
+        Self::require_keeper(&env, &group, &caller)?;
+
+        let mut settlement: Settlement = env
+            .storage().persistent().get(&DataKey::Settlement(group_id.clone()))
+            .ok_or(Error::NoRefundAvailable)?;
+
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        if settlement.next_index >= members.len() {
+            return Err(Error::NoRefundAvailable);
         }
 
-        // Fisher-Yates shuffle with ledger PRNG
-        let mut i = total_members;
-        while i > 1 {
-            i -= 1;
-            // Use PRNG to pick a random index in [0, i]
-            let rand_val: u32 = env
-                .prng()
-                .gen_range::<u64>(0..(total_members as u64 + 1)) as u32;
-            let j = rand_val % (i + 1);
+        let token = group_token(&env, &group)?;
+        let token_client = token::Client::new(&env, &token);
+        let end = core::cmp::min(settlement.next_index.saturating_add(batch_size), members.len());
+
+        for i in settlement.next_index..end {
+            let member_addr = members.get(i).unwrap();
+            let claim = Self::settlement_claim(&env, &group_id, &member_addr)?;
+            let amount = if settlement.total_claims <= settlement.pot {
+                claim
+            } else {
+                claim
+                    .checked_mul(settlement.pot)
+                    .ok_or(Error::ArithmeticOverflow)?
+                    / settlement.total_claims
+            };
 
-            let temp_i = order.get(i).unwrap();
-            let temp_j = order.get(j).unwrap();
-            order.set(i, temp_j);
-            order.set(j, temp_i);
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &member_addr, &amount);
+                adjust_escrow(&env, &group_id, -amount)?;
+                events::Settled {
+                    group_id: group_id.clone(),
+                    member: member_addr.clone(),
+                    amount,
+                }
+                .publish(&env);
+            }
+            Self::release_collateral(&env, &group_id, &group, &member_addr, &member_addr)?;
         }
 
-        order
+        settlement.next_index = end;
+        env.storage().persistent().set(&DataKey::Settlement(group_id.clone()), &settlement);
+
+        Ok(members.len() - end)
     }
 
-    fn distribute_payout(env: &Env, group_id: String) -> Result<(), Error> {
+    /// Contributes the required amount for the current round of an active group.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction.
+    /// - The group must exist and be in `Active` status.
+    /// - The caller must be a member of the group.
+    /// - The member must not have already paid for the current round.
+    /// - The member must not be in `Defaulted` status.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotActive` if the group is not active.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::MemberDefaulted` if the member has defaulted.
+    /// - `Error::AlreadyPaidThisRound` if the member already paid for this round.
+    /// - `Error::PaymentWindowClosed` if the current time is past the grace period after the deadline.
+    /// - `Error::ArithmeticOverflow` if the contribution amount causes an overflow.
+    ///
+    /// # Behavior
+    /// - Transfers the contribution from the member into contract custody, using
+    ///   the native XLM Stellar Asset Contract when no token address is set.
+    /// - Records the contribution and updates the member's status to `PaidCurrentRound`.
+    /// - If the member pays after the deadline but within the grace period, status becomes
+    ///   `Overdue` and the group's late fee, if any, is charged on top.
+    /// - If the grace period has passed, the member is marked as `Defaulted`.
+    /// - Under `DefaultPolicy::TopUp`, adds the member's pro-rata share of the
+    ///   contributions owed by members who defaulted before this round.
+    /// - Sets aside the group's `reserve_bps` of `contribution_amount` in the
+    ///   reserve; the rest is recorded as the member's contribution to the pot.
+    /// - If every member has paid or defaulted, triggers payout distribution.
+    /// - Publishes a `contrib` event, including the late fee charged.
+    pub fn contribute(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
         let group: SavingsGroup = env
             .storage().persistent().get(&DataKey::Group(group_id.clone()))
             .ok_or(Error::GroupNotFound)?;
 
+        bump_group_keys(&env, &group_id);
+
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+
+        let mut member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+
+        if member_data.status == MemberStatus::Defaulted {
+            return Err(Error::MemberDefaulted);
+        }
+        if member_data.status == MemberStatus::PaidCurrentRound {
+            return Err(Error::AlreadyPaidThisRound);
+        }
+
         let current_round = group.current_round;
+        let deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), current_round))
+            .unwrap_or(0);
 
-        let total_pool = group
-            .contribution_amount
-            .checked_mul(group.total_members as i128)
-            .ok_or(Error::ArithmeticOverflow)?;
-        // #639: Integer division truncates the remainder (dust). The truncated
-        // fraction of a unit is implicitly kept by the payout recipient — this is
-        // intentional and favors the recipient over the platform.
-        let platform_fee = (total_pool * (group.platform_fee_percent as i128)) / 10000;
-        let payout_amount = total_pool
-            .checked_sub(platform_fee)
-            .ok_or(Error::ArithmeticOverflow)?;
+        let deadline_with_grace = deadline
+            .checked_add(group.grace_period)
+            .ok_or(Error::Overflow)?;
+
+        // AUDIT FIX (#740, #741): The original code wrote MemberStatus::Defaulted to
+        // persistent storage and then returned Err(Error::PaymentWindowClosed). In
+        // Soroban, returning Err causes the host to revert *all* storage writes made
+        // within the same invocation, so that write was silently discarded and
+        // MemberStatus::Defaulted was never actually set in production. The orphaned
+        // write has been removed; late-contribution callers now get PaymentWindowClosed
+        // and the separate mark_defaulted() admin function is responsible for
+        // transitioning the member to Defaulted status.
+        if env.ledger().timestamp() > deadline_with_grace {
+            return Err(Error::PaymentWindowClosed);
+        }
 
-        let recipient =             Self::get_next_payout_recipient(env, group_id.clone(), current_round)?;
+        let mut late_fee: i128 = 0;
+        if env.ledger().timestamp() > deadline {
+            member_data.status = MemberStatus::Overdue;
+            env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
+            late_fee = Self::charge_late_fee(&env, &group_id, &group, &member, deadline)?;
+        }
 
-        // #606: pay the recipient real funds from the contract's custody
-        // when the group is denominated in a SEP-41 token.
-        if let Some(token) = group.token_address.clone() {
-            token::Client::new(&env, &token).transfer(
-                &env.current_contract_address(),
-                &recipient,
-                &payout_amount,
-            );
+        let amount = group
+            .contribution_amount
+            .checked_add(Self::top_up_share(&env, &group_id, &group)?)
+            .ok_or(Error::ArithmeticOverflow)?;
+        let reserve_cut = Self::reserve_cut(&group);
+
+        // #606: move real funds from the member into the contract's custody.
+        // Native XLM groups go through the Stellar Asset Contract like any
+        // other SEP-41 token.
+        let token = group_token(&env, &group)?;
+        token::Client::new(&env, &token).transfer(
+            &member,
+            env.current_contract_address(),
+            &amount,
+        );
+        adjust_escrow(&env, &group_id, amount - reserve_cut)?;
+        if reserve_cut > 0 {
+            Self::add_to_reserve(&env, &group_id, &member, reserve_cut)?;
         }
 
-        let payout = Payout {
-            recipient: recipient.clone(),
-            amount: payout_amount,
+        let contribution = Contribution {
+            member: member.clone(),
+            amount: amount - reserve_cut,
             round: current_round,
             timestamp: env.ledger().timestamp(),
         };
 
-        let mut payouts: Vec<Payout> = env
-            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), current_round))
+        let mut round_contributions: Vec<Contribution> = env
+            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), current_round))
             .unwrap_or(Vec::new(&env));
-        payouts.push_back(payout);
-        env.storage().persistent().set(&DataKey::Payouts(group_id.clone(), current_round), &payouts);
+        round_contributions.push_back(contribution);
+        env.storage().persistent().set(&DataKey::Contributions(group_id.clone(), current_round), &round_contributions);
 
-        let mut recipient_data: Member = env
-            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), recipient.clone()))
-            .ok_or(Error::RecipientNotFound)?;
-        recipient_data.has_received_payout = true;
-        recipient_data.payout_round = current_round;
-        recipient_data.status = MemberStatus::ReceivedPayout;
+        member_data.status = MemberStatus::PaidCurrentRound;
+        member_data.total_contributed = member_data
+            .total_contributed
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
+        bump_member_key(&env, &group_id, &member);
+
+        env.events().publish(
+            (symbol_short!("contrib"),),
+            (group_id.clone(), member, amount, current_round, late_fee),
+        );
+
+        Self::distribute_if_settled(&env, &group_id, &group, None)
+    }
+
+    /// Forcefully ends the current round when the group has stalled past the grace period.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction and be allowed to act as a keeper.
+    /// - The group must exist and be in `Active` status.
+    /// - The current time must be past the deadline plus the group's grace period.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotKeeper` if the group restricts keepers and the caller is not one.
+    /// - `Error::GroupNotActive` if the group is not active.
+    /// - `Error::RoundNotStalled` if the grace period has not yet elapsed.
+    ///
+    /// # Behavior
+    /// - Marks every member who has not paid the current round as `Defaulted`,
+    ///   including members who already received their payout.
+    /// - Distributes the payout to the eligible recipient. If the scheduled
+    ///   recipient has defaulted, the round closes without a payout and its
+    ///   contributions become claimable through `claim_refund`.
+    /// - Pays the caller the group's keeper bounty out of the distributed round's fee.
+    /// - Pauses the group after distributing the payout; see `resume_group`.
+    /// - Publishes a `paused` event.
+    pub fn force_end_round(env: Env, caller: Address, group_id: String) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        Self::require_keeper(&env, &group, &caller)?;
+
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+
+        let current_round = group.current_round;
+        let deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), current_round))
+            .unwrap_or(0);
+
+        if env.ledger().timestamp() <= deadline + group.grace_period {
+            return Err(Error::RoundNotStalled);
+        }
+
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+
+        for member_addr in members.iter() {
+            let mut member_data: Member = env
+                .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+                .unwrap();
+
+            // Members who already received their payout still owe every
+            // round, so default on "has not paid" rather than on status.
+            if member_data.status != MemberStatus::Defaulted
+                && !Self::has_contributed(&env, &group_id, current_round, &member_addr)
+            {
+                member_data.status = MemberStatus::Defaulted;
+                env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member_addr), &member_data);
+            }
+        }
+
+        let _ = Self::distribute_payout(&env, group_id.clone(), Some(caller));
+
+        // A successful distribution has already closed the round, and after
+        // the last round completed and settled the group.
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        if group.status != GroupStatus::Completed {
+            group.status = GroupStatus::Paused;
+            if group.current_round != current_round {
+                // Closed by the distribution.
+            } else if group.current_round >= group.total_members {
+                group.status = GroupStatus::Completed;
+                Self::settle_collateral(&env, &group_id, &group)?;
+                Self::settle_reserve(&env, &group_id, &group)?;
+                sync_registry_closed(&env, &group_id, false);
+            } else {
+                group.current_round += 1;
+                let deadline = Self::calculate_deadline(&env, &group, group.current_round);
+                env.storage().persistent().set(
+                    &DataKey::RoundDeadline(group_id.clone(), group.current_round),
+                    &deadline,
+                );
+            }
+        }
+
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        if group.status == GroupStatus::Paused {
+            env.storage().persistent().set(&DataKey::PausedAt(group_id.clone()), &env.ledger().timestamp());
+        }
+
+        env.events().publish((symbol_short!("paused"),), group_id);
+        Ok(())
+    }
+
+    /// Pauses an active savings group.
+    ///
+    /// # Preconditions
+    /// - Caller must be the admin and authorize the transaction.
+    /// - The group must exist and be in `Active` status.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotActive` if the group is not active.
+    ///
+    /// # Behavior
+    /// - Sets the group status to `Paused` and records when, so that
+    ///   `resume_group` can push deadlines back by the time spent paused.
+    /// - Publishes a `paused` event.
+    pub fn pause_group(env: Env, admin: Address, group_id: String) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+
+        group.status = GroupStatus::Paused;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        env.storage().persistent().set(&DataKey::PausedAt(group_id.clone()), &env.ledger().timestamp());
+
+        env.events().publish((symbol_short!("paused"),), group_id);
+        Ok(())
+    }
+
+    /// Resumes a paused savings group.
+    ///
+    /// # Preconditions
+    /// - Caller must be the admin and authorize the transaction.
+    /// - The group must exist and be in `Paused` status.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotActive` if the group is not paused.
+    ///
+    /// # Behavior
+    /// - Sets the group status back to `Active`.
+    /// - Pushes the current and all later round deadlines back by the time
+    ///   the group spent paused, so nobody can be defaulted for it.
+    /// - Publishes a `resumed` event.
+    pub fn resume_group(env: Env, admin: Address, group_id: String) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Paused {
+            return Err(Error::GroupNotActive);
+        }
+
+        group.status = GroupStatus::Active;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        if let Some(paused_at) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::PausedAt(group_id.clone()))
+        {
+            env.storage().persistent().remove(&DataKey::PausedAt(group_id.clone()));
+            Self::shift_deadlines(&env, &group_id, &group, env.ledger().timestamp() - paused_at)?;
+        }
+
+        env.events().publish((symbol_short!("resumed"),), group_id);
+        Ok(())
+    }
+
+    /// Proposes pushing the current round's deadline, and every later one,
+    /// back by `seconds`, e.g. around a festival or a bank outage. Replaces a
+    /// proposal left over from an earlier round and counts as the proposer's
+    /// vote.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotActive` if the group is neither Active nor Paused.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::InvalidConfig` if `seconds` is zero or exceeds
+    ///   `MAX_EXTENSION_SECONDS`, or a proposal is already open this round.
+    pub fn propose_extension(
+        env: Env,
+        member: Address,
+        group_id: String,
+        seconds: u64,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Active && group.status != GroupStatus::Paused {
+            return Err(Error::GroupNotActive);
+        }
+        if !env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::NotMember);
+        }
+        if seconds == 0 || seconds > MAX_EXTENSION_SECONDS {
+            return Err(Error::InvalidConfig);
+        }
+        if let Some(open) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ExtensionProposal>(&DataKey::Extension(group_id.clone()))
+        {
+            if open.round == group.current_round {
+                return Err(Error::InvalidConfig);
+            }
+        }
+
+        let proposal = ExtensionProposal {
+            round: group.current_round,
+            seconds,
+            votes: Vec::from_array(&env, [member.clone()]),
+        };
+        env.storage().persistent().set(&DataKey::Extension(group_id.clone()), &proposal);
+
+        events::ExtensionProposed {
+            group_id: group_id.clone(),
+            member,
+            round: group.current_round,
+            seconds,
+        }
+        .publish(&env);
+
+        Self::tally_extension(&env, &group_id, &group, proposal)
+    }
+
+    /// Votes for the open deadline extension proposal of the current round.
+    /// Voting twice is a no-op.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotActive` if the group is neither Active nor Paused.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::NoProposal` if no proposal is open for the current round.
+    ///
+    /// # Behavior
+    /// - Publishes an `ext_vote` event, and an `extended` event once a strict
+    ///   majority of members has voted and the deadlines have moved.
+    pub fn vote_extension(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Active && group.status != GroupStatus::Paused {
+            return Err(Error::GroupNotActive);
+        }
+        if !env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::NotMember);
+        }
+        let mut proposal: ExtensionProposal = env
+            .storage().persistent().get(&DataKey::Extension(group_id.clone()))
+            .filter(|p: &ExtensionProposal| p.round == group.current_round)
+            .ok_or(Error::NoProposal)?;
+        if proposal.votes.contains(&member) {
+            return Ok(());
+        }
+
+        proposal.votes.push_back(member.clone());
+        env.storage().persistent().set(&DataKey::Extension(group_id.clone()), &proposal);
+
+        events::ExtensionVoted {
+            group_id: group_id.clone(),
+            member,
+            votes: proposal.votes.len(),
+        }
+        .publish(&env);
+
+        Self::tally_extension(&env, &group_id, &group, proposal)
+    }
+
+    /// Removes a member from an open savings group.
+    ///
+    /// # Preconditions
+    /// - Caller must be the admin and authorize the transaction.
+    /// - The group must exist and be in `Open` status.
+    /// - The member must be part of the group and have zero contributions.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status.
+    /// - `Error::NotMember` if the specified member is not part of the group.
+    /// - `Error::MemberDefaulted` if the member has already made contributions.
+    /// - `Error::RevealPending` if a CommitReveal group's reveal phase has
+    ///   started; a newcomer could no longer commit.
+    ///
+    /// # Behavior
+    /// - Returns the member's collateral and removes their data from storage.
+    /// - Removes the member from the group's members list and their user
+    ///   groups list, and shifts later members' `join_order` down by one.
+    /// - Withdraws a proposed `PayoutMode::Fixed` order and its approvals,
+    ///   which the shift invalidates; the admin proposes it again.
+    /// - Decrements the member count.
+    /// - Before the start timestamp, admits the head of the waitlist.
+    /// - Publishes a `removed` event.
+    pub fn remove_member(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        member: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotAcceptingMembers);
+        }
+
+        let member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+
+        if member_data.total_contributed > 0 {
+            return Err(Error::MemberDefaulted);
+        }
+
+        Self::drop_member(&env, &group_id, &group, &member_data)?;
+        Self::promote_waitlist(&env, &group_id)?;
+
+        env.events().publish((symbol_short!("removed"),), (group_id, member));
+        Ok(())
+    }
+
+    /// Leaves an open group before its start timestamp, e.g. after joining
+    /// by mistake.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotAcceptingMembers` if the group is not in `Open` status.
+    /// - `Error::StartDateAlreadyPassed` if the current time is past the start timestamp.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::AdminCannotLeave` if the caller is the group admin, who must
+    ///   `transfer_admin` or `cancel_group` instead.
+    /// - `Error::RevealPending` if a CommitReveal group's reveal phase has
+    ///   started.
+    ///
+    /// # Behavior
+    /// - Cleans up exactly as `remove_member` does, returning the collateral
+    ///   and admitting the head of the waitlist.
+    /// - Publishes a `left` event.
+    pub fn leave_group(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotAcceptingMembers);
+        }
+        if env.ledger().timestamp() >= group.start_timestamp {
+            return Err(Error::StartDateAlreadyPassed);
+        }
+        let member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+        if member == group.admin {
+            return Err(Error::AdminCannotLeave);
+        }
+
+        Self::drop_member(&env, &group_id, &group, &member_data)?;
+        Self::promote_waitlist(&env, &group_id)?;
+
+        events::Left { group_id, member }.publish(&env);
+        Ok(())
+    }
+
+    /// Hands `from`'s seat to `to` so the cycle can carry on when a member
+    /// emigrates or dies partway through it. Both must authorize, and the
+    /// admin too when the group vets its members (a private group, or a
+    /// `JoinPolicy` other than `Open`).
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotActive` if the group is Completed or Cancelled.
+    /// - `Error::NotMember` if `from` is not a member of the group.
+    /// - `Error::AdminCannotLeave` if `from` is the group admin, who must
+    ///   `transfer_admin` first.
+    /// - `Error::AlreadyMember` if `to` is already a member, is waitlisted or
+    ///   has a pending join request.
+    ///
+    /// # Behavior
+    /// - Moves the `Member` record (join order, contributions, payout state
+    ///   and status) to `to`, along with the seat's collateral, reserve
+    ///   share, dividends, open swap offer, sealed bid and votes.
+    /// - Re-attributes the seat's contributions, payouts and refunds so far
+    ///   to `to`, so refunds and settlement follow the seat.
+    /// - Updates `Members`, both users' group lists and the registry.
+    /// - Publishes a `seat_xfer` event.
+    pub fn transfer_seat(env: Env, group_id: String, from: Address, to: Address) -> Result<(), Error> {
+        from.require_auth();
+        to.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status == GroupStatus::Completed || group.status == GroupStatus::Cancelled {
+            return Err(Error::GroupNotActive);
+        }
+        if !group.is_public || group.join_policy != JoinPolicy::Open {
+            group.admin.require_auth();
+        }
+        let member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), from.clone()))
+            .ok_or(Error::NotMember)?;
+        if from == group.admin {
+            return Err(Error::AdminCannotLeave);
+        }
+        let waitlisted = Self::get_waitlist(env.clone(), group_id.clone()).contains(&to);
+        if waitlisted
+            || env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), to.clone()))
+            || env.storage().persistent().has(&DataKey::JoinRequest(group_id.clone(), to.clone()))
+        {
+            return Err(Error::AlreadyMember);
+        }
+
+        Self::move_seat(&env, &group_id, &group, member_data, &from, &to);
+
+        events::SeatTransferred { group_id, from, to }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets a group's platform fee (in basis points), e.g. `0` for a fee-free
+    /// community group, in place of the one chosen at creation. It must be
+    /// chosen while the admin is the only member, so nobody joins under one
+    /// fee and pays another.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if other members have already joined, or the
+    ///   fee exceeds the contract's maximum fee.
+    pub fn set_group_fee(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        fee_bps: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count > 1 || fee_bps > Self::max_fee(&env) {
+            return Err(Error::InvalidConfig);
+        }
+
+        group.platform_fee_percent = fee_bps;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        events::GroupFeeSet { group_id, fee_bps }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets the collateral every member escrows when joining, in the group's
+    /// token; `0` disables it. It must be chosen while the admin is the only
+    /// member, and the admin's own collateral is posted (or returned) here.
+    ///
+    /// Under any `DefaultPolicy`, a member who misses a round has one
+    /// contribution slashed from their collateral to cover it. Collateral is
+    /// returned when the group completes, except that what remains of a
+    /// defaulted member's goes to the treasury.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if other members have already joined, or the
+    ///   amount is negative or below `contribution_amount`.
+    pub fn set_collateral(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        amount: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count > 1 {
+            return Err(Error::InvalidConfig);
+        }
+        Self::check_collateral(amount, group.contribution_amount)?;
+
+        Self::release_collateral(&env, &group_id, &group, &admin, &admin)?;
+        Self::post_collateral(&env, &group_id, &group, &admin, amount)?;
+
+        group.collateral_amount = amount;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        events::CollateralSet { group_id, collateral_amount: amount }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets how a group covers the contributions of members who default; see
+    /// `DefaultPolicy`. Chosen while the group is Open and locked once it is
+    /// Active.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    pub fn set_default_policy(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        policy: DefaultPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+
+        group.default_policy = policy.clone();
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        events::DefaultPolicySet { group_id, policy }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets the share of each contribution (basis points) a group sets aside
+    /// in its insurance reserve. With `DefaultPolicy::Reserve` the reserve
+    /// covers defaulted members' contributions; what is left when the group
+    /// completes is returned to members in good standing in proportion to
+    /// what they paid in. Chosen while the group is Open.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if `reserve_bps` exceeds 10,000.
+    pub fn set_reserve_bps(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        reserve_bps: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        if reserve_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidConfig);
+        }
+
+        group.reserve_bps = reserve_bps;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        events::ReserveBpsSet { group_id, reserve_bps }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets (or clears, with `None`) the fee charged for contributing after a
+    /// round's deadline but within its grace period. Chosen while the group
+    /// is Open.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if a flat fee is negative or a bps fee exceeds 10,000.
+    pub fn set_late_fee(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        config: Option<LateFeeConfig>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+
+        let key = DataKey::LateFee(group_id.clone());
+        match &config {
+            Some(late_fee) => {
+                Self::check_late_fee(late_fee)?;
+                env.storage().persistent().set(&key, late_fee);
+                env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        events::LateFeeSet { group_id, config }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets how long after each round's deadline members may still contribute
+    /// before they can be marked defaulted. Chosen while the group is Open.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if `seconds` exceeds `MAX_GRACE_PERIOD_SECONDS`
+    ///   or is shorter than the group's auction bid window or commit-reveal window.
+    pub fn set_grace_period(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        seconds: u64,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        Self::check_grace_period(&group.payout_mode, seconds)?;
+
+        group.grace_period = seconds;
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        events::GracePeriodSet { group_id, grace_period: seconds }.publish(&env);
+        Ok(())
+    }
+
+    /// Sets how a group picks each round's recipient, e.g. a bidding ROSCA
+    /// with `PayoutMode::Auction`. Like the fee, the mode is chosen while the
+    /// group is still Open and locked once it is Active.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if an auction's `bid_window` or a commit-reveal
+    ///   window is zero or longer than the grace period.
+    pub fn set_payout_mode(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        mode: PayoutMode,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        Self::check_payout_mode(&mode, group.grace_period)?;
+
+        group.payout_mode = mode.clone();
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        events::PayoutModeSet { group_id: group_id.clone(), mode }.publish(&env);
+
+        // A full Fixed group waiting for approval activates once it no longer needs it.
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count == group.total_members {
+            Self::try_activate(&env, &group_id)?;
+        }
+        Ok(())
+    }
+
+    /// Proposes the payout order of a `PayoutMode::Fixed` group:
+    /// `order[i]` is the join_order of the member paid in round i+1. The
+    /// proposal replaces any earlier one and counts as the admin's approval.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::InvalidConfig` if the group is not in Fixed mode or `order`
+    ///   is not a permutation of `0..total_members`.
+    ///
+    /// # Behavior
+    /// - Resets approvals and publishes an `ord_prop` event.
+    /// - Activates the group if it is already full and the proposal is approved.
+    pub fn propose_payout_order(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        order: Vec<u32>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        if group.payout_mode != PayoutMode::Fixed
+            || !Self::is_permutation(&order, group.total_members)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage().persistent().set(&DataKey::ProposedOrder(group_id.clone()), &order);
+        env.storage().persistent().set(
+            &DataKey::OrderApprovals(group_id.clone()),
+            &Vec::from_array(&env, [admin.clone()]),
+        );
+
+        events::OrderProposed { group_id: group_id.clone(), order }.publish(&env);
+
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count == group.total_members {
+            Self::try_activate(&env, &group_id)?;
+        }
+        Ok(())
+    }
+
+    /// Approves the proposed payout order of a `PayoutMode::Fixed` group.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::InvalidConfig` if no order has been proposed.
+    ///
+    /// # Behavior
+    /// - Records the approval; approving twice is a no-op.
+    /// - Publishes an `ord_ok` event.
+    /// - Activates the group once it is full and a strict majority of
+    ///   `total_members` has approved the current proposal.
+    pub fn approve_payout_order(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        if !env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::NotMember);
+        }
+        if !env.storage().persistent().has(&DataKey::ProposedOrder(group_id.clone())) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut approvals: Vec<Address> = env
+            .storage().persistent().get(&DataKey::OrderApprovals(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        if approvals.contains(&member) {
+            return Ok(());
+        }
+        approvals.push_back(member.clone());
+        env.storage().persistent().set(&DataKey::OrderApprovals(group_id.clone()), &approvals);
+
+        events::OrderApproved {
+            group_id: group_id.clone(),
+            member,
+            approvals: approvals.len(),
+        }
+        .publish(&env);
+
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count == group.total_members {
+            Self::try_activate(&env, &group_id)?;
+        }
+        Ok(())
+    }
+
+    /// Commits `sha256(secret)` towards the payout order of a
+    /// `PayoutMode::CommitReveal` group. Every member must commit while the
+    /// group is Open; re-committing replaces the earlier commitment. The
+    /// reveal phase starts once the group is full and everyone has committed,
+    /// so no member's secret, and no public value, decides the order alone.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotOpen` if the group is no longer Open.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::InvalidConfig` if the group is not in CommitReveal mode.
+    /// - `Error::RevealPending` once the reveal phase has started.
+    pub fn commit_seed(
+        env: Env,
+        member: Address,
+        group_id: String,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        if !env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::NotMember);
+        }
+        if !matches!(group.payout_mode, PayoutMode::CommitReveal(_)) {
+            return Err(Error::InvalidConfig);
+        }
+        if env.storage().persistent().has(&DataKey::RevealDeadline(group_id.clone())) {
+            return Err(Error::RevealPending);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::SeedCommit(group_id.clone(), member.clone()), &commitment);
+
+        events::SeedCommitted { group_id: group_id.clone(), member, commitment }.publish(&env);
+
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if member_count == group.total_members {
+            Self::try_activate(&env, &group_id)?;
+        }
+        Ok(())
+    }
+
+    /// Reveals the secret behind a member's `commit_seed` commitment during
+    /// the reveal phase that starts when a CommitReveal group fills up.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotOpen` if the group has already activated.
+    /// - `Error::InvalidConfig` if no reveal phase is running, the member made
+    ///   no commitment, or `secret` does not hash to it.
+    /// - `Error::PaymentWindowClosed` if the reveal window has passed.
+    ///
+    /// # Behavior
+    /// - Stores the secret and publishes a `seed_rvl` event.
+    /// - Activates the group once every member has revealed.
+    pub fn reveal_seed(
+        env: Env,
+        member: Address,
+        group_id: String,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        let reveal_deadline: u64 = env
+            .storage().persistent().get(&DataKey::RevealDeadline(group_id.clone()))
+            .ok_or(Error::InvalidConfig)?;
+        if env.ledger().timestamp() >= reveal_deadline {
+            return Err(Error::PaymentWindowClosed);
+        }
+        let commitment: BytesN<32> = env
+            .storage().persistent().get(&DataKey::SeedCommit(group_id.clone(), member.clone()))
+            .ok_or(Error::InvalidConfig)?;
+        let digest: BytesN<32> = env.crypto().sha256(&Bytes::from(secret.clone())).into();
+        if digest != commitment {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::SeedReveal(group_id.clone(), member.clone()), &secret);
+
+        events::SeedRevealed { group_id: group_id.clone(), member, secret }.publish(&env);
+
+        Self::try_activate(&env, &group_id)?;
+        Ok(())
+    }
+
+    /// Activates a full CommitReveal group once its reveal window has passed,
+    /// substituting the commitment of each member who did not reveal.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotKeeper` if the group restricts keepers and the caller is not one.
+    /// - `Error::GroupNotOpen` if the group has already activated.
+    /// - `Error::RevealPending` if no reveal phase is running or its window
+    ///   has not passed yet.
+    pub fn finalize_seed(env: Env, caller: Address, group_id: String) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        Self::require_keeper(&env, &group, &caller)?;
+
+        if group.status != GroupStatus::Open {
+            return Err(Error::GroupNotOpen);
+        }
+        let reveal_deadline: u64 = env
+            .storage().persistent().get(&DataKey::RevealDeadline(group_id.clone()))
+            .ok_or(Error::RevealPending)?;
+        if env.ledger().timestamp() < reveal_deadline {
+            return Err(Error::RevealPending);
+        }
+
+        Self::try_activate(&env, &group_id)?;
+        Ok(())
+    }
+
+    /// Offers to trade the caller's payout position (`my_round`) for
+    /// `their_round`, optionally paying `premium` to whoever holds it. Any
+    /// earlier offer by the caller is withdrawn and its premium returned.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` / `Error::GroupNotActive` if the group does not
+    ///   exist or is not Active.
+    /// - `Error::InvalidRound` if either round is out of range or already paid.
+    /// - `Error::InvalidSwap` if the group is an auction, the rounds are equal,
+    ///   the caller does not hold `my_round`, or `premium` is negative.
+    ///
+    /// # Behavior
+    /// - Moves `premium` into the contract's custody and publishes a
+    ///   `swap_prop` event.
+    pub fn propose_swap(
+        env: Env,
+        member: Address,
+        group_id: String,
+        my_round: u32,
+        their_round: u32,
+        premium: i128,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        Self::require_swappable(&env, &group_id, &group, my_round, their_round)?;
+        if premium < 0 || Self::round_holder(&env, &group_id, &group, my_round) != Some(member.clone()) {
+            return Err(Error::InvalidSwap);
+        }
+
+        let token = token::Client::new(&env, &group_token(&env, &group)?);
+        if let Some(previous) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, SwapOffer>(&DataKey::SwapOffer(group_id.clone(), member.clone()))
+        {
+            if previous.premium > 0 {
+                token.transfer(&env.current_contract_address(), &member, &previous.premium);
+            }
+        }
+        if premium > 0 {
+            token.transfer(&member, env.current_contract_address(), &premium);
+        }
+
+        let offer = SwapOffer {
+            proposer: member.clone(),
+            my_round,
+            their_round,
+            premium,
+        };
+        let key = DataKey::SwapOffer(group_id.clone(), member.clone());
+        env.storage().persistent().set(&key, &offer);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+
+        events::SwapProposed {
+            group_id,
+            proposer: member,
+            my_round,
+            their_round,
+            premium,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accepts `proposer`'s swap offer. The caller must hold the offer's
+    /// `their_round`; the two members trade places in `payout_order`.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` / `Error::GroupNotActive` if the group does not
+    ///   exist or is not Active.
+    /// - `Error::InvalidSwap` if there is no such offer or either member no
+    ///   longer holds their round.
+    /// - `Error::InvalidRound` if either round has been paid since the offer.
+    ///
+    /// # Behavior
+    /// - Swaps the two positions, pays the premium to the caller and
+    ///   publishes a `swapped` event.
+    pub fn accept_swap(
+        env: Env,
+        member: Address,
+        group_id: String,
+        proposer: Address,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        let key = DataKey::SwapOffer(group_id.clone(), proposer.clone());
+        let offer: SwapOffer = env
+            .storage().persistent().get(&key)
+            .ok_or(Error::InvalidSwap)?;
+
+        Self::require_swappable(&env, &group_id, &group, offer.my_round, offer.their_round)?;
+        if Self::round_holder(&env, &group_id, &group, offer.my_round) != Some(proposer.clone())
+            || Self::round_holder(&env, &group_id, &group, offer.their_round) != Some(member.clone())
+        {
+            return Err(Error::InvalidSwap);
+        }
+
+        let mine = group.payout_order.get(offer.my_round - 1).unwrap();
+        let theirs = group.payout_order.get(offer.their_round - 1).unwrap();
+        group.payout_order.set(offer.my_round - 1, theirs);
+        group.payout_order.set(offer.their_round - 1, mine);
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+        env.storage().persistent().remove(&key);
+
+        if offer.premium > 0 {
+            token::Client::new(&env, &group_token(&env, &group)?).transfer(
+                &env.current_contract_address(),
+                &member,
+                &offer.premium,
+            );
+        }
+
+        events::Swapped {
+            group_id,
+            proposer,
+            accepter: member,
+            my_round: offer.my_round,
+            their_round: offer.their_round,
+            premium: offer.premium,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraws the caller's open swap offer and returns its premium.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::InvalidSwap` if the caller has no open offer.
+    pub fn cancel_swap(env: Env, member: Address, group_id: String) -> Result<(), Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        let key = DataKey::SwapOffer(group_id.clone(), member.clone());
+        let offer: SwapOffer = env
+            .storage().persistent().get(&key)
+            .ok_or(Error::InvalidSwap)?;
+        env.storage().persistent().remove(&key);
+
+        if offer.premium > 0 {
+            token::Client::new(&env, &group_token(&env, &group)?).transfer(
+                &env.current_contract_address(),
+                &member,
+                &offer.premium,
+            );
+        }
+
+        events::SwapCancelled { group_id, proposer: member }.publish(&env);
+        Ok(())
+    }
+
+    /// Places an open bid for the current round of an auction group.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` / `Error::GroupNotActive` if the group does not
+    ///   exist or is not Active.
+    /// - `Error::NotMember` if the caller is not a member.
+    /// - `Error::InvalidBid` if the group does not run open auctions, the
+    ///   bidder was already paid or defaulted, `discount_bps` exceeds 10,000,
+    ///   or the bid does not beat the current top bid.
+    /// - `Error::BiddingClosed` outside the bidding window.
+    ///
+    /// # Behavior
+    /// - Records the bid as the round's top bid and publishes a `bid` event.
+    pub fn place_bid(env: Env, member: Address, group_id: String, discount_bps: u32) -> Result<(), Error> {
+        member.require_auth();
+
+        let (group, config, deadline) = Self::require_bidder(&env, &group_id, &member)?;
+        if config.sealed {
+            return Err(Error::InvalidBid);
+        }
+        if !Self::in_window(&env, deadline.saturating_sub(config.bid_window), deadline) {
+            return Err(Error::BiddingClosed);
+        }
+
+        Self::record_bid(&env, &group_id, group.current_round, &member, discount_bps)
+    }
+
+    /// Commits a sealed bid, `sha256(discount_bps (big-endian u32) || salt)`,
+    /// for the current round of an auction group. Re-committing replaces the
+    /// earlier commitment.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` / `Error::GroupNotActive` if the group does not
+    ///   exist or is not Active.
+    /// - `Error::NotMember` if the caller is not a member.
+    /// - `Error::InvalidBid` if the group does not run sealed auctions or the
+    ///   bidder was already paid or defaulted.
+    /// - `Error::BiddingClosed` outside the bidding window.
+    pub fn commit_bid(
+        env: Env,
+        member: Address,
+        group_id: String,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let (group, config, deadline) = Self::require_bidder(&env, &group_id, &member)?;
+        if !config.sealed {
+            return Err(Error::InvalidBid);
+        }
+        if !Self::in_window(&env, deadline.saturating_sub(config.bid_window), deadline) {
+            return Err(Error::BiddingClosed);
+        }
+
+        let key = DataKey::BidCommit(group_id.clone(), group.current_round, member.clone());
+        env.storage().persistent().set(&key, &commitment);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+
+        events::BidCommitted { group_id, round: group.current_round, bidder: member }.publish(&env);
+        Ok(())
+    }
+
+    /// Reveals a sealed bid committed with `commit_bid`, during the
+    /// `bid_window` seconds after the round deadline.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` / `Error::GroupNotActive` if the group does not
+    ///   exist or is not Active.
+    /// - `Error::NotMember` if the caller is not a member.
+    /// - `Error::InvalidBid` if the group does not run sealed auctions, the
+    ///   bidder was already paid or defaulted, no matching commitment exists,
+    ///   `discount_bps` exceeds 10,000, or the bid does not beat the top bid.
+    /// - `Error::BiddingClosed` outside the reveal window.
+    ///
+    /// # Behavior
+    /// - Consumes the commitment, records the bid as the round's top bid and
+    ///   publishes a `bid` event.
+    pub fn reveal_bid(
+        env: Env,
+        member: Address,
+        group_id: String,
+        discount_bps: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        member.require_auth();
+
+        let (group, config, deadline) = Self::require_bidder(&env, &group_id, &member)?;
+        if !config.sealed {
+            return Err(Error::InvalidBid);
+        }
+        if !Self::in_window(&env, deadline, deadline.saturating_add(config.bid_window)) {
+            return Err(Error::BiddingClosed);
+        }
+
+        let key = DataKey::BidCommit(group_id.clone(), group.current_round, member.clone());
+        let commitment: BytesN<32> = env
+            .storage().persistent().get(&key)
+            .ok_or(Error::InvalidBid)?;
+        let mut preimage = Bytes::from_array(&env, &discount_bps.to_be_bytes());
+        preimage.append(&Bytes::from(salt));
+        let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if digest != commitment {
+            return Err(Error::InvalidBid);
+        }
+        env.storage().persistent().remove(&key);
+
+        Self::record_bid(&env, &group_id, group.current_round, &member, discount_bps)
+    }
+
+    /// Transfers admin rights of a savings group to a new address.
+    ///
+    /// # Preconditions
+    /// - Caller must be the current admin and authorize the transaction.
+    /// - The group must exist.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the current group admin.
+    ///
+    /// # Behavior
+    /// - Updates the group's admin to the new address.
+    /// - Adds the group to the new admin's user groups list.
+    /// - Pushes the new admin to the registry, if one is configured.
+    /// - Publishes an `adm_xfer` event.
+    pub fn transfer_admin(
+        env: Env,
+        group_id: String,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
+        current_admin.require_auth();
+
+        let mut group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != current_admin {
+            return Err(Error::AdminOnly);
+        }
+
+        group.admin = new_admin.clone();
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        let mut new_admin_groups: Vec<String> = env
+            .storage().persistent().get(&DataKey::UserGroups(new_admin.clone()))
+            .unwrap_or(Vec::new(&env));
+        new_admin_groups.push_back(group_id.clone());
+        env.storage().persistent().set(&DataKey::UserGroups(new_admin.clone()), &new_admin_groups);
+
+        if let Some(registry) = registry_client(&env) {
+            registry.sync_admin(&env.current_contract_address(), &group_id, &new_admin);
+        }
+
+        env.events().publish(
+            (symbol_short!("adm_xfer"),),
+            (group_id, current_admin, new_admin),
+        );
+        Ok(())
+    }
+
+    /// Configures who may act as a keeper for a group and the bounty they earn.
+    ///
+    /// # Preconditions
+    /// - Caller must be the group admin and authorize the transaction.
+    /// - `config.bounty_bps` must not exceed 10,000 (the whole platform fee).
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::AdminOnly` if the caller is not the group admin.
+    /// - `Error::InvalidConfig` if the bounty exceeds the platform fee.
+    ///
+    /// # Behavior
+    /// - Replaces the group's keeper configuration.
+    /// - Publishes a `keepers` event.
+    pub fn set_keeper_config(
+        env: Env,
+        admin: Address,
+        group_id: String,
+        config: KeeperConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.admin != admin {
+            return Err(Error::AdminOnly);
+        }
+        if config.bounty_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidConfig);
+        }
+
+        env.storage().persistent().set(&DataKey::KeeperConfig(group_id.clone()), &config);
+        env.storage().persistent().extend_ttl(
+            &DataKey::KeeperConfig(group_id.clone()),
+            GROUP_TTL_EXTEND,
+            GROUP_TTL_EXTEND,
+        );
+
+        events::KeeperConfigSet {
+            group_id,
+            restricted: config.restricted,
+            bounty_bps: config.bounty_bps,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Allows a defaulted member to pay catch-up contributions and return to active status.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction.
+    /// - The group must exist and be in `Active` status.
+    /// - The caller must be a member of the group with `Defaulted` status.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::GroupNotActive` if the group is not active.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::NotDefaulted` if the member is not in `Defaulted` status.
+    /// - `Error::CatchUpRequired` if there are no missed rounds to catch up on
+    ///   and no slashed collateral to restore.
+    /// - `Error::ArithmeticOverflow` if the catch-up amount causes an overflow.
+    ///
+    /// # Behavior
+    /// - Missed rounds are the closed rounds the member has no `Contribution`
+    ///   for, plus the current round once its payment window has closed. This
+    ///   is derived from the contribution audit trail, so it is correct for
+    ///   members who have already received their payout.
+    /// - Transfers `contribution_amount * missed_rounds` from the member and
+    ///   records a `Contribution` for each missed round. The current round's
+    ///   share joins its pot; each closed round's share is paid to that
+    ///   round's recipient, or to the reserve if the round had no payout.
+    /// - Charges the group's late fee, if any, once per missed round against
+    ///   that round's deadline.
+    /// - Tops the member's collateral back up to the group's `collateral_amount`.
+    /// - Updates the member's total contributions and sets status back to
+    ///   `Active` (or `PaidCurrentRound` if the current round was caught up).
+    /// - Triggers payout distribution if the catch-up settles the current round.
+    /// - Publishes a `cured` event with the catch-up amount and missed rounds.
+    pub fn cure_default(
+        env: Env,
+        member: Address,
+        group_id: String,
+    ) -> Result<(), Error> {
+        member.require_auth();
+        Self::catch_up(env, member, group_id, true)
+    }
+
+    /// `cure_default` without the authorization check, shared with
+    /// `fill_defaulted_seat`. Unless `required`, a seat with nothing to catch
+    /// up on is simply restored to good standing.
+    fn catch_up(env: Env, member: Address, group_id: String, required: bool) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+
+        let mut member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+
+        if member_data.status != MemberStatus::Defaulted {
+            return Err(Error::NotDefaulted);
+        }
+
+        let current_round = group.current_round;
+        let now = env.ledger().timestamp();
+
+        let mut missed: Vec<u32> = Vec::new(&env);
+        for round in 1..current_round {
+            if !Self::has_contributed(&env, &group_id, round, &member) {
+                missed.push_back(round);
+            }
+        }
+        let current_deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), current_round))
+            .unwrap_or(0);
+        let current_window_closed = now > current_deadline.saturating_add(group.grace_period);
+        if current_window_closed && !Self::has_contributed(&env, &group_id, current_round, &member) {
+            missed.push_back(current_round);
+        }
+
+        let missed_rounds = missed.len();
+        let collateral: i128 = env
+            .storage().persistent().get(&DataKey::Collateral(group_id.clone(), member.clone()))
+            .unwrap_or(0);
+        let collateral_shortfall = group.collateral_amount - collateral;
+        if required && missed_rounds == 0 && collateral_shortfall <= 0 {
+            return Err(Error::CatchUpRequired);
+        }
+
+        let catch_up_amount = group
+            .contribution_amount
+            .checked_mul(missed_rounds as i128)
+            .ok_or(Error::ArithmeticOverflow)?;
+
+        let token_client = token::Client::new(&env, &group_token(&env, &group)?);
+        if catch_up_amount > 0 {
+            token_client.transfer(
+                &member,
+                env.current_contract_address(),
+                &catch_up_amount,
+            );
+        }
+        if collateral_shortfall > 0 {
+            Self::post_collateral(&env, &group_id, &group, &member, collateral_shortfall)?;
+        }
+
+        for round in missed.iter() {
+            let mut round_contributions: Vec<Contribution> = env
+                .storage().persistent().get(&DataKey::Contributions(group_id.clone(), round))
+                .unwrap_or(Vec::new(&env));
+            round_contributions.push_back(Contribution {
+                member: member.clone(),
+                amount: group.contribution_amount,
+                round,
+                timestamp: now,
+            });
+            env.storage().persistent().set(&DataKey::Contributions(group_id.clone(), round), &round_contributions);
+
+            // The current round's pot has not been paid out yet; a closed
+            // round's has, so its share goes out straight away.
+            if round == current_round {
+                adjust_escrow(&env, &group_id, group.contribution_amount)?;
+            } else {
+                Self::pay_late_round(&env, &group_id, &token_client, &member, round, group.contribution_amount)?;
+            }
+
+            let deadline: u64 = env
+                .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), round))
+                .unwrap_or(0);
+            Self::charge_late_fee(&env, &group_id, &group, &member, deadline)?;
+        }
+
+        member_data.total_contributed = member_data
+            .total_contributed
+            .checked_add(catch_up_amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        member_data.status = if Self::has_contributed(&env, &group_id, current_round, &member) {
+            MemberStatus::PaidCurrentRound
+        } else {
+            MemberStatus::Active
+        };
+
+        env.storage().persistent().set(
+            &DataKey::MemberData(group_id.clone(), member.clone()),
+            &member_data,
+        );
+        bump_member_key(&env, &group_id, &member);
+
+        env.events().publish(
+            (symbol_short!("cured"),),
+            (member, group_id.clone(), catch_up_amount, missed_rounds),
+        );
+
+        Self::distribute_if_settled(&env, &group_id, &group, None)
+    }
+
+    /// Pay `member`'s late contribution to a closed `round` to the member who
+    /// was short-paid that round. It goes to the group's reserve instead when
+    /// the reserve covered the contribution, or when the round closed without
+    /// a payout and there is nobody to make whole.
+    fn pay_late_round(
+        env: &Env,
+        group_id: &String,
+        token_client: &token::Client,
+        member: &Address,
+        round: u32,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let cover_key = DataKey::ReserveCover(group_id.clone(), round, member.clone());
+        let covered = env.storage().persistent().has(&cover_key);
+        env.storage().persistent().remove(&cover_key);
+        let payouts: Vec<Payout> = env
+            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), round))
+            .unwrap_or(Vec::new(env));
+        let to = match payouts.last() {
+            Some(payout) if !covered => {
+                token_client.transfer(&env.current_contract_address(), &payout.recipient, &amount);
+                payout.recipient
+            }
+            _ => {
+                Self::credit_reserve(env, group_id, amount)?;
+                env.current_contract_address()
+            }
+        };
+
+        events::LatePaid {
+            group_id: group_id.clone(),
+            member: member.clone(),
+            to,
+            amount,
+            round,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Retries payout distribution for the current round if all members have
+    /// paid or defaulted.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction and be allowed to act as a keeper.
+    /// - The group must exist and be in `Active` status.
+    /// - Every member must have contributed for the current round or defaulted.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotKeeper` if the group restricts keepers and the caller is not one.
+    /// - `Error::GroupNotActive` if the group is not active.
+    /// - `Error::NotAllPaid` if a member in good standing has not contributed.
+    /// - `Error::BiddingOpen` if the group is an auction and bidding for the
+    ///   current round has not closed yet.
+    ///
+    /// # Behavior
+    /// - If payouts have already been distributed for the current round, returns early.
+    /// - Otherwise, triggers the payout distribution for the current round and
+    ///   pays the caller the group's keeper bounty.
+    pub fn retry_distribution(env: Env, caller: Address, group_id: String) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        Self::require_keeper(&env, &group, &caller)?;
+
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+
+        let current_round = group.current_round;
+        if !Self::all_members_paid(&env, group_id.clone(), current_round) {
+            return Err(Error::NotAllPaid);
+        }
+
+        let existing_payouts: Vec<Payout> = env
+            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), current_round))
+            .unwrap_or(Vec::new(&env));
+
+        if !existing_payouts.is_empty() {
+            return Ok(());
+        }
+        if !Self::bidding_closed(&env, &group_id, &group) {
+            return Err(Error::BiddingOpen);
+        }
+
+        Self::distribute_payout(&env, group_id, Some(caller))
+    }
+
+    /// Claims a refund of a member's contribution to a round that closed
+    /// without a payout.
+    ///
+    /// A round's escrow is refundable only once nobody can receive it: the
+    /// round was force-ended past its grace period (or the group completed)
+    /// and no payout was recorded for it, e.g. because the scheduled
+    /// recipient had defaulted. Rounds that paid out are never refundable,
+    /// since the pool has already left custody.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction.
+    /// - The group and member must exist.
+    /// - The member must have contributed for the specified round.
+    /// - The round must be closed and have no recorded payout.
+    /// - The member must not have already claimed a refund for that round.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotMember` if the caller is not a member of the group.
+    /// - `Error::NoRefundAvailable` if the member did not contribute, the round
+    ///   is still open or paid out, or the refund was already claimed.
+    ///
+    /// # Behavior
+    /// - Transfers the contributed amount from contract custody back to the member.
+    /// - Records the refund so it can only be claimed once and deducts it from
+    ///   the member's `total_contributed`.
+    /// - Publishes a `refunded` event and returns the refunded amount.
+    pub fn claim_refund(
+        env: Env,
+        member: Address,
+        group_id: String,
+        round: u32,
+    ) -> Result<i128, Error> {
+        member.require_auth();
+
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        let mut member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+
+        // Cancelled groups return escrow through settle_cancelled instead.
+        if group.status == GroupStatus::Cancelled {
+            return Err(Error::NoRefundAvailable);
+        }
+
+        let refund_key = DataKey::Refunded(group_id.clone(), round, member.clone());
+        if env.storage().persistent().has(&refund_key) {
+            return Err(Error::NoRefundAvailable);
+        }
+
+        if !Self::is_round_closed(&group, round) {
+            return Err(Error::NoRefundAvailable);
+        }
+
+        let payouts: Vec<Payout> = env
+            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), round))
+            .unwrap_or(Vec::new(&env));
+        if !payouts.is_empty() {
+            return Err(Error::NoRefundAvailable);
+        }
+
+        let contributions: Vec<Contribution> = env
+            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), round))
+            .unwrap_or(Vec::new(&env));
+
+        // Catch-up payments made through cure_default land after the round's
+        // payment window and settle a debt, so they are not refundable.
+        let deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), round))
+            .unwrap_or(0);
+        let window_close = deadline.saturating_add(group.grace_period);
+
+        let mut contributed_amount: i128 = 0;
+        for contrib in contributions.iter() {
+            if contrib.member == member && contrib.timestamp <= window_close {
+                contributed_amount = contrib.amount;
+                break;
+            }
+        }
+
+        if contributed_amount == 0 {
+            return Err(Error::NoRefundAvailable);
+        }
+
+        let token = group_token(&env, &group)?;
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &member,
+            &contributed_amount,
+        );
+        adjust_escrow(&env, &group_id, -contributed_amount)?;
+
+        env.storage().persistent().set(&refund_key, &true);
+        env.storage().persistent().extend_ttl(&refund_key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+
+        member_data.total_contributed = member_data
+            .total_contributed
+            .checked_sub(contributed_amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
+
+        events::Refunded { group_id, member, amount: contributed_amount, round }.publish(&env);
+
+        Ok(contributed_amount)
+    }
+
+    /// Marks a member as defaulted if the grace period for the current round has elapsed.
+    ///
+    /// # Preconditions
+    /// - Caller must authorize the transaction and be allowed to act as a keeper.
+    /// - The group must exist and be in `Active` status.
+    /// - The member must be part of the group.
+    /// - The current time must be past the deadline plus the grace period.
+    ///
+    /// # Errors
+    /// - `Error::GroupNotFound` if the group does not exist.
+    /// - `Error::NotKeeper` if the group restricts keepers and the caller is not one.
+    /// - `Error::NotMember` if the member is not part of the group.
+    /// - `Error::GroupNotActive` if the group is not active.
+    ///
+    /// # Behavior
+    /// - If the member is already defaulted or has paid the current round, returns early.
+    ///   Members who already received their payout can still be defaulted.
+    /// - Sets the member's status to `Defaulted` if the grace period has elapsed.
+    /// - Publishes a `defaulted` event with the member and current round.
+    /// - Distributes the round, paying the caller the keeper bounty, if every
+    ///   other member has paid or defaulted.
+    pub fn mark_defaulted(
+        env: Env,
+        caller: Address,
+        member: Address,
+        group_id: String,
+    ) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        Self::require_keeper(&env, &group, &caller)?;
+
+        let mut member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+
+        if member_data.status == MemberStatus::Defaulted
+            || Self::has_contributed(&env, &group_id, group.current_round, &member)
+        {
+            return Ok(());
+        }
+
+        let deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), group.current_round))
+            .unwrap_or(0);
+
+        if env.ledger().timestamp() > deadline + group.grace_period {
+            member_data.status = MemberStatus::Defaulted;
+            env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &member_data);
+
+            env.events().publish(
+                (symbol_short!("defaulted"),),
+                (member, group.current_round),
+            );
+
+            Self::distribute_if_settled(&env, &group_id, &group, Some(caller))?;
+        }
+
+        Ok(())
+    }
+
+    // ─── Internal helpers ────────────────────────────────────────────
+
+    /// Activate a full group: fix its payout order, start round 1 and set the
+    /// first deadline. Returns false, leaving the group Open, while a
+    /// `PayoutMode::Fixed` group's proposed order lacks majority approval or
+    /// a `PayoutMode::CommitReveal` group is still collecting commitments or
+    /// reveals.
+    fn try_activate(env: &Env, group_id: &String) -> Result<bool, Error> {
+        let mut group: SavingsGroup = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        let payout_order = if group.payout_mode == PayoutMode::Fixed {
+            match Self::approved_order(env, group_id, &group) {
+                Some(order) => order,
+                None => return Ok(false),
+            }
+        } else if let PayoutMode::CommitReveal(window) = group.payout_mode {
+            match Self::committed_order(env, group_id, &group, window) {
+                Some(order) => order,
+                None => return Ok(false),
+            }
+        } else {
+            // #744/#745: Generate pseudorandom payout order so the admin
+            // is NOT deterministically first. Uses Fisher-Yates shuffle
+            // seeded by the ledger's PRNG to produce an unbiased ordering.
+            Self::generate_payout_order(env, group.total_members)
+        };
+
+        group.status = GroupStatus::Active;
+        group.current_round = 1;
+        group.payout_order = payout_order;
+
+        env.storage().persistent().set(&DataKey::Group(group_id.clone()), &group);
+
+        let deadline = Self::calculate_deadline(env, &group, 1);
+        env.storage().persistent().set(&DataKey::RoundDeadline(group_id.clone(), 1), &deadline);
+        Ok(true)
+    }
+
+    /// The proposed order of a Fixed group, if a strict majority of
+    /// `total_members` who are still members has approved it.
+    fn approved_order(env: &Env, group_id: &String, group: &SavingsGroup) -> Option<Vec<u32>> {
+        let order: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProposedOrder(group_id.clone()))?;
+        let approvals: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OrderApprovals(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+
+        let mut approved: u32 = 0;
+        for member_addr in approvals.iter() {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::MemberData(group_id.clone(), member_addr))
+            {
+                approved += 1;
+            }
+        }
+        if approved * 2 > group.total_members {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// The payout order of a full CommitReveal group, or None while
+    /// commitments or reveals are still due. The first call after every
+    /// member has committed starts the reveal window; once every member has
+    /// revealed, or the window has passed, the order is derived and its
+    /// `OrderTranscript` stored.
+    fn committed_order(env: &Env, group_id: &String, group: &SavingsGroup, window: u64) -> Option<Vec<u32>> {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+
+        let mut transcript = OrderTranscript {
+            members: Vec::new(env),
+            inputs: Vec::new(env),
+            revealed: Vec::new(env),
+            seed: BytesN::from_array(env, &[0; 32]),
+        };
+        let mut all_revealed = true;
+        for member_addr in members.iter() {
+            let commitment: BytesN<32> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::SeedCommit(group_id.clone(), member_addr.clone()))?;
+            let reveal: Option<BytesN<32>> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::SeedReveal(group_id.clone(), member_addr.clone()));
+            all_revealed &= reveal.is_some();
+            transcript.members.push_back(member_addr);
+            transcript.revealed.push_back(reveal.is_some());
+            transcript.inputs.push_back(reveal.unwrap_or(commitment));
+        }
+
+        let now = env.ledger().timestamp();
+        let key = DataKey::RevealDeadline(group_id.clone());
+        match env.storage().persistent().get::<DataKey, u64>(&key) {
+            None => {
+                let deadline = now.saturating_add(window);
+                env.storage().persistent().set(&key, &deadline);
+                events::RevealOpened {
+                    group_id: group_id.clone(),
+                    reveal_deadline: deadline,
+                }
+                .publish(env);
+                return None;
+            }
+            Some(deadline) if !all_revealed && now < deadline => return None,
+            _ => {}
+        }
+
+        let mut preimage = group_id.to_bytes();
+        for input in transcript.inputs.iter() {
+            preimage.append(&Bytes::from(input));
+        }
+        transcript.seed = env.crypto().sha256(&preimage).into();
+        let order = Self::seeded_payout_order(env, &transcript.seed, group.total_members);
+
+        env.storage().persistent().set(&DataKey::OrderTranscript(group_id.clone()), &transcript);
+        env.storage().persistent().extend_ttl(
+            &DataKey::OrderTranscript(group_id.clone()),
+            GROUP_TTL_EXTEND,
+            GROUP_TTL_EXTEND,
+        );
+        Some(order)
+    }
+
+    /// Fisher-Yates shuffle of `0..total_members` drawing each swap index
+    /// from `sha256(seed || u32_be(i))`, so anyone holding the seed can
+    /// recompute it.
+    fn seeded_payout_order(env: &Env, seed: &BytesN<32>, total_members: u32) -> Vec<u32> {
+        let mut order: Vec<u32> = Vec::new(env);
+        for i in 0..total_members {
+            order.push_back(i);
+        }
+
+        let mut i = total_members;
+        while i > 1 {
+            i -= 1;
+            let mut data = Bytes::from(seed.clone());
+            data.append(&Bytes::from_array(env, &i.to_be_bytes()));
+            let digest = env.crypto().sha256(&data).to_array();
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&digest[0..8]);
+            let j = (u64::from_be_bytes(word) % (i as u64 + 1)) as u32;
+
+            let temp_i = order.get(i).unwrap();
+            let temp_j = order.get(j).unwrap();
+            order.set(i, temp_j);
+            order.set(j, temp_i);
+        }
+
+        order
+    }
+
+    /// Whether `order` holds every value in `0..total_members` exactly once.
+    fn is_permutation(order: &Vec<u32>, total_members: u32) -> bool {
+        if order.len() != total_members {
+            return false;
+        }
+        // Bitmask of values seen (MAX_MEMBERS <= 32).
+        let mut seen: u32 = 0;
+        for value in order.iter() {
+            if value >= total_members || seen & (1 << value) != 0 {
+                return false;
+            }
+            seen |= 1 << value;
+        }
+        true
+    }
+
+    /// #744/#745: Generate a pseudorandom payout order using Fisher-Yates
+    /// shuffle seeded by the ledger's PRNG.  This ensures no member
+    /// (including the admin) is deterministically first in the payout
+    /// sequence, eliminating the admin-first rug-pull vector.
+    fn generate_payout_order(env: &Env, total_members: u32) -> Vec<u32> {
+        let mut order: Vec<u32> = Vec::new(env);
+        for i in 0..total_members {
+            order.push_back(i);
+        }
+
+        // Fisher-Yates shuffle with ledger PRNG
+        let mut i = total_members;
+        while i > 1 {
+            i -= 1;
+            // Use PRNG to pick a random index in [0, i]
+            let rand_val: u32 = env
+                .prng()
+                .gen_range::<u64>(0..(total_members as u64 + 1)) as u32;
+            let j = rand_val % (i + 1);
+
+            let temp_i = order.get(i).unwrap();
+            let temp_j = order.get(j).unwrap();
+            order.set(i, temp_j);
+            order.set(j, temp_i);
+        }
+
+        order
+    }
+
+    fn distribute_payout(env: &Env, group_id: String, keeper: Option<Address>) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+
+        let current_round = group.current_round;
+
+        // Resolved before anything is written: callers may treat
+        // `NoRecipientFound` as "leave the round for force_end_round".
+        let (recipient, discount_bps) = match &group.payout_mode {
+            PayoutMode::Auction(_) => Self::auction_winner(env, &group_id, &group)?,
+            _ => (Self::get_next_payout_recipient(env, group_id.clone(), current_round)?, 0),
+        };
+
+        let covered = Self::cover_defaults(env, &group_id, &group)?;
+
+        // The pool is what the contract actually took custody of this round.
+        // It equals contribution_amount * total_members when everyone paid, and
+        // is smaller when a defaulter's contribution is missing and the
+        // group's `DefaultPolicy` did not cover it, so escrow belonging to
+        // other rounds is never paid out.
+        let round_contributions: Vec<Contribution> = env
+            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), current_round))
+            .unwrap_or(Vec::new(env));
+        let mut total_pool: i128 = covered;
+        for contrib in round_contributions.iter() {
+            total_pool = total_pool
+                .checked_add(contrib.amount)
+                .ok_or(Error::ArithmeticOverflow)?;
+        }
+        // #639: Integer division truncates the remainder (dust). The truncated
+        // fraction of a unit is implicitly kept by the payout recipient — this is
+        // intentional and favors the recipient over the platform.
+        let platform_fee = (total_pool * (group.platform_fee_percent as i128)) / (BPS_DENOMINATOR as i128);
+        let payout_amount = total_pool
+            .checked_sub(platform_fee)
+            .ok_or(Error::ArithmeticOverflow)?;
+
+        // An auction winner gives up their discount, shared equally among the
+        // other non-defaulted members; the division's dust stays with the winner.
+        let discount = (payout_amount * (discount_bps as i128)) / (BPS_DENOMINATOR as i128);
+        let mut dividend_members: Vec<Address> = Vec::new(env);
+        if discount > 0 {
+            let members: Vec<Address> = env
+                .storage().persistent().get(&DataKey::Members(group_id.clone()))
+                .unwrap_or(Vec::new(env));
+            for member_addr in members.iter() {
+                if member_addr == recipient {
+                    continue;
+                }
+                if let Some(data) = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+                {
+                    if data.status != MemberStatus::Defaulted {
+                        dividend_members.push_back(member_addr);
+                    }
+                }
+            }
+        }
+        let dividend = if dividend_members.is_empty() {
+            0
+        } else {
+            discount / (dividend_members.len() as i128)
+        };
+        let recipient_amount = payout_amount
+            .checked_sub(dividend * (dividend_members.len() as i128))
+            .ok_or(Error::ArithmeticOverflow)?;
+
+        // #606: pay the recipient real funds from the contract's custody.
+        let token = group_token(env, &group)?;
+        let token_client = token::Client::new(env, &token);
+        if recipient_amount > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &recipient_amount,
+            );
+        }
+        if dividend > 0 {
+            for member_addr in dividend_members.iter() {
+                token_client.transfer(&env.current_contract_address(), &member_addr, &dividend);
+                let key = DataKey::Dividends(group_id.clone(), member_addr);
+                let received: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+                let received = received.checked_add(dividend).ok_or(Error::ArithmeticOverflow)?;
+                env.storage().persistent().set(&key, &received);
+                env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+            }
+        }
+        if let PayoutMode::Auction(_) = &group.payout_mode {
+            events::AuctionWon {
+                group_id: group_id.clone(),
+                round: current_round,
+                winner: recipient.clone(),
+                discount_bps,
+                dividend,
+            }
+            .publish(env);
+        }
+
+        // The payout, bounty and fee together consume the whole pool.
+        adjust_escrow(env, &group_id, -total_pool)?;
+
+        // A keeper that closed the round is paid its bounty out of the fee.
+        let mut treasury_fee = platform_fee;
+        if let Some(keeper) = keeper {
+            let config = Self::keeper_config(env, &group_id);
+            let bounty = (platform_fee * (config.bounty_bps as i128)) / (BPS_DENOMINATOR as i128);
+            if bounty > 0 {
+                token_client.transfer(&env.current_contract_address(), &keeper, &bounty);
+                treasury_fee = treasury_fee
+                    .checked_sub(bounty)
+                    .ok_or(Error::ArithmeticOverflow)?;
+                events::BountyPaid {
+                    group_id: group_id.clone(),
+                    keeper,
+                    amount: bounty,
+                    round: current_round,
+                }
+                .publish(env);
+            }
+        }
+
+        // The platform fee leaves custody in the same invocation as the payout
+        // so nothing accrues in the contract without a way to withdraw it.
+        if treasury_fee > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &group.treasury,
+                &treasury_fee,
+            );
+
+            let collected: i128 = env
+                .storage().persistent().get(&DataKey::FeesCollected(group_id.clone()))
+                .unwrap_or(0);
+            let collected = collected
+                .checked_add(treasury_fee)
+                .ok_or(Error::ArithmeticOverflow)?;
+            env.storage().persistent().set(&DataKey::FeesCollected(group_id.clone()), &collected);
+
+            events::FeePaid {
+                group_id: group_id.clone(),
+                treasury: group.treasury.clone(),
+                amount: treasury_fee,
+                round: current_round,
+            }
+            .publish(env);
+        }
+
+        let payout = Payout {
+            recipient: recipient.clone(),
+            amount: recipient_amount,
+            round: current_round,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        let mut payouts: Vec<Payout> = env
+            .storage().persistent().get(&DataKey::Payouts(group_id.clone(), current_round))
+            .unwrap_or(Vec::new(&env));
+        payouts.push_back(payout);
+        env.storage().persistent().set(&DataKey::Payouts(group_id.clone(), current_round), &payouts);
+
+        let mut recipient_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), recipient.clone()))
+            .ok_or(Error::RecipientNotFound)?;
+        recipient_data.has_received_payout = true;
+        recipient_data.payout_round = current_round;
+        recipient_data.status = MemberStatus::ReceivedPayout;
         env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), recipient.clone()), &recipient_data);
 
-        // #752: include group_id so the activity feed can attribute this payout
-        env.events().publish(
-            (symbol_short!("payout"),),
-            (group_id.clone(), recipient, payout_amount, current_round),
-        );
+        // #752: include group_id so the activity feed can attribute this payout
+        env.events().publish(
+            (symbol_short!("payout"),),
+            (group_id.clone(), recipient, recipient_amount, current_round),
+        );
+
+        Self::end_round(env, group_id, group)?;
+
+        Ok(())
+    }
+
+    // #637: This function performs O(n) storage reads and writes per round close
+    // (one per member) to reset PaidCurrentRound back to Active. For a 20-member
+    // group this means up to 40 storage operations every round. A future
+    // optimization could track "paid this round" via a per-round Vec/Set rather
+    // than a persistent per-member status flag, eliminating the reset loop.
+    fn end_round(env: &Env, group_id: String, mut group: SavingsGroup) -> Result<(), Error> {
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+
+        for member_addr in members.iter() {
+            if let Some(mut member_data) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+            {
+                if member_data.status == MemberStatus::PaidCurrentRound {
+                    member_data.status = MemberStatus::Active;
+                }
+                // Keep Defaulted and ReceivedPayout as is
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::MemberData(group_id.clone(), member_addr.clone()), &member_data);
+            }
+        }
+
+        if group.current_round >= group.total_members {
+            group.status = GroupStatus::Completed;
+            Self::settle_collateral(env, &group_id, &group)?;
+            Self::settle_reserve(env, &group_id, &group)?;
+            sync_registry_closed(env, &group_id, false);
+        } else {
+            group.current_round += 1;
+            let deadline = Self::calculate_deadline(env, &group, group.current_round);
+            env.storage().persistent().set(&DataKey::RoundDeadline(group_id.clone(), group.current_round), &deadline);
+        }
+
+        env.storage().persistent().set(&DataKey::Group(group_id), &group);
+
+        // #636: Use saturating_sub to guard against underflow if current_round is ever 0.
+        // Currently unreachable (current_round starts at 1), but protects against
+        // future changes to round-numbering logic.
+        let ended_round = group.current_round.saturating_sub(1);
+        env.events()
+            .publish((symbol_short!("round_end"),), ended_round);
+
+        Ok(())
+    }
+
+    /// Internal: create and persist a new member record, push to the members
+    /// vec, and increment the member count. Returns the new member count.
+    fn add_member_to_group(
+        env: &Env,
+        member: &Address,
+        group_id: &String,
+    ) -> u32 {
+        let member_count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+
+        let new_member = Member {
+            address: member.clone(),
+            join_timestamp: env.ledger().timestamp(),
+            join_order: member_count,
+            status: MemberStatus::Active,
+            total_contributed: 0,
+            has_received_payout: false,
+            payout_round: 0,
+        };
+
+        env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &new_member);
+
+        let mut members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        members.push_back(member.clone());
+        env.storage().persistent().set(&DataKey::Members(group_id.clone()), &members);
+
+        let new_count = member_count + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::MemberCount(group_id.clone()), &new_count);
+
+        new_count
+    }
+
+    fn add_admin_to_group(env: &Env, member: Address, group_id: String) -> Result<(), Error> {
+        // Guard: admin must not already be a member of this group
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::MemberData(group_id.clone(), member.clone()))
+        {
+            return Err(Error::AlreadyMember);
+        }
+
+        let new_count = Self::add_member_to_group(env, &member, &group_id);
+
+        env.events()
+            .publish((symbol_short!("joined"),), (member.clone(), new_count));
+
+        // #750: include group_id in the admin's joined event
+        env.events().publish(
+            (symbol_short!("joined"),),
+            (group_id.clone(), member, new_count),
+        );
+        Ok(())
+    }
+
+    /// A round's scheduled deadline, pushed back by any extensions and
+    /// pauses so far (`DataKey::DeadlineShift`).
+    fn calculate_deadline(env: &Env, group: &SavingsGroup, round: u32) -> u64 {
+        let shift: u64 = env
+            .storage().persistent().get(&DataKey::DeadlineShift(group.group_id.clone()))
+            .unwrap_or(0);
+        let round_duration = match group.frequency {
+            Frequency::Daily => 86400,
+            Frequency::Weekly => 604800,
+            Frequency::BiWeekly => 1209600,
+            Frequency::Monthly => 2592000,
+            Frequency::Custom(seconds) => seconds,
+            Frequency::CalendarMonthly => return add_months(group.start_timestamp, round) + shift,
+        };
+        group.start_timestamp + (round as u64 * round_duration) + shift
+    }
+
+    /// A round is closed once the group has moved past it, or the group has
+    /// completed; contributions to a closed round can no longer be paid out
+    /// through the normal round flow.
+    fn is_round_closed(group: &SavingsGroup, round: u32) -> bool {
+        if round == 0 || round > group.current_round {
+            return false;
+        }
+        round < group.current_round || group.status == GroupStatus::Completed
+    }
+
+    /// The version whose schema the stored records follow.
+    fn schema_version(env: &Env) -> String {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Version)
+            .unwrap_or(String::from_str(env, LEGACY_CONTRACT_VERSION))
+    }
+
+    /// Build the `payout_order` that reproduces the legacy join-order
+    /// fallback of `get_next_payout_recipient`: rounds already paid keep
+    /// their recipient, and each remaining round goes to the lowest unpaid
+    /// join_order at or after `round - 1`.
+    fn legacy_payout_order(env: &Env, group_id: &String, group: &SavingsGroup) -> Result<Vec<u32>, Error> {
+        // join_order of each round's recorded recipient, u32::MAX when the
+        // round has not paid out.
+        let mut paid: Vec<u32> = Vec::new(env);
+        // Bitmask of join_orders already placed (MAX_MEMBERS <= 32).
+        let mut used: u32 = 0;
+        for round in 1..=group.total_members {
+            let mut recipient_order = u32::MAX;
+            if round < group.current_round {
+                let payouts: Vec<Payout> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Payouts(group_id.clone(), round))
+                    .unwrap_or(Vec::new(env));
+                if let Some(payout) = payouts.get(0) {
+                    let data: Member = env
+                        .storage()
+                        .persistent()
+                        .get(&DataKey::MemberData(group_id.clone(), payout.recipient))
+                        .ok_or(Error::MemberDataMissing)?;
+                    recipient_order = data.join_order;
+                    used |= 1 << data.join_order;
+                }
+            }
+            paid.push_back(recipient_order);
+        }
+
+        let mut order: Vec<u32> = Vec::new(env);
+        for round in 1..=group.total_members {
+            let recorded = paid.get(round - 1).unwrap();
+            if recorded != u32::MAX {
+                order.push_back(recorded);
+                continue;
+            }
+            let mut pick = (round - 1..group.total_members).find(|jo| used & (1 << jo) == 0);
+            if pick.is_none() {
+                pick = (0..group.total_members).find(|jo| used & (1 << jo) == 0);
+            }
+            let jo = pick.ok_or(Error::NoRecipientFound)?;
+            used |= 1 << jo;
+            order.push_back(jo);
+        }
+        Ok(order)
+    }
+
+    fn require_contract_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let contract_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotAdmin)?;
+        if contract_admin != *admin {
+            return Err(Error::NotAdmin);
+        }
+        Ok(())
+    }
+
+    fn default_fee(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DefaultFee)
+            .unwrap_or(DEFAULT_PLATFORM_FEE_BPS)
+    }
+
+    fn max_fee(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxFee)
+            .unwrap_or(DEFAULT_MAX_PLATFORM_FEE_BPS)
+    }
+
+    /// Remove a group from the global groups list and every member's
+    /// UserGroups list.
+    fn unindex_group(env: &Env, group_id: &String) {
+        let mut all_groups: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AllGroups)
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = all_groups.first_index_of(group_id) {
+            all_groups.remove(idx);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllGroups, &all_groups);
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+
+        for member_addr in members.iter() {
+            Self::remove_user_group(env, &member_addr, group_id);
+        }
+    }
+
+    /// Undo a member's enrolment in an Open group: return their collateral,
+    /// drop their records, index entries and seed, keep the remaining
+    /// `join_order`s contiguous and withdraw any proposed payout order.
+    fn drop_member(env: &Env, group_id: &String, group: &SavingsGroup, member_data: &Member) -> Result<(), Error> {
+        if env.storage().persistent().has(&DataKey::RevealDeadline(group_id.clone())) {
+            return Err(Error::RevealPending);
+        }
+        let member = &member_data.address;
+        Self::release_collateral(env, group_id, group, member, member)?;
+        env.storage().persistent().remove(&DataKey::MemberData(group_id.clone(), member.clone()));
+        env.storage().persistent().remove(&DataKey::SeedCommit(group_id.clone(), member.clone()));
+        env.storage().persistent().remove(&DataKey::SeedReveal(group_id.clone(), member.clone()));
+
+        let mut members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = members.first_index_of(member) {
+            members.remove(idx);
+        }
+        env.storage().persistent().set(&DataKey::Members(group_id.clone()), &members);
+
+        for addr in members.iter() {
+            let key = DataKey::MemberData(group_id.clone(), addr);
+            if let Some(mut other) = env.storage().persistent().get::<DataKey, Member>(&key) {
+                if other.join_order > member_data.join_order {
+                    other.join_order -= 1;
+                    env.storage().persistent().set(&key, &other);
+                }
+            }
+        }
+
+        // A proposed payout order indexes members by join order, which has
+        // just shifted, so it no longer pays whom its approvers agreed to.
+        env.storage().persistent().remove(&DataKey::ProposedOrder(group_id.clone()));
+        env.storage().persistent().remove(&DataKey::OrderApprovals(group_id.clone()));
+
+        let count: u32 = env
+            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
+            .unwrap_or(0);
+        if count > 0 {
+            env.storage().persistent().set(&DataKey::MemberCount(group_id.clone()), &(count - 1));
+        }
+        Self::remove_user_group(env, member, group_id);
+        sync_registry_member(env, group_id, member, false);
+        Ok(())
+    }
+
+    /// Whether a group queues joiners once full: public groups that admit
+    /// without approval.
+    fn has_waitlist(group: &SavingsGroup) -> bool {
+        group.is_public && group.join_policy == JoinPolicy::Open
+    }
+
+    /// Escrow `member`'s collateral and append them to the waitlist.
+    fn enqueue_waitlist(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        member: &Address,
+    ) -> Result<(), Error> {
+        if env.storage().persistent().has(&DataKey::MemberData(group_id.clone(), member.clone())) {
+            return Err(Error::AlreadyMember);
+        }
+        let key = DataKey::Waitlist(group_id.clone());
+        let mut queue: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if queue.contains(member) {
+            return Ok(());
+        }
+        if queue.len() >= MAX_WAITLIST {
+            return Err(Error::GroupIsFull);
+        }
+
+        Self::post_collateral(env, group_id, group, member, group.collateral_amount)?;
+        queue.push_back(member.clone());
+        env.storage().persistent().set(&key, &queue);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+
+        events::Waitlisted {
+            group_id: group_id.clone(),
+            member: member.clone(),
+            position: queue.len(),
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Admit the head of the waitlist to a seat freed before the start.
+    fn promote_waitlist(env: &Env, group_id: &String) -> Result<(), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        if group.status != GroupStatus::Open || env.ledger().timestamp() >= group.start_timestamp {
+            return Ok(());
+        }
+        let key = DataKey::Waitlist(group_id.clone());
+        let mut queue: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        let Some(next) = queue.get(0) else {
+            return Ok(());
+        };
+        queue.remove(0);
+        env.storage().persistent().set(&key, &queue);
+
+        events::Promoted { group_id: group_id.clone(), member: next.clone() }.publish(env);
+        Self::enroll_member(env, group_id, &next)
+    }
+
+    /// Hand the `Member` record and everything else the seat holds from
+    /// `from` to `to`, and update the member and user indexes.
+    fn move_seat(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        mut member_data: Member,
+        from: &Address,
+        to: &Address,
+    ) {
+        env.storage().persistent().remove(&DataKey::MemberData(group_id.clone(), from.clone()));
+        member_data.address = to.clone();
+        env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), to.clone()), &member_data);
+        bump_member_key(env, group_id, to);
+
+        let mut members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = members.first_index_of(from) {
+            members.set(idx, to.clone());
+        }
+        env.storage().persistent().set(&DataKey::Members(group_id.clone()), &members);
+
+        Self::reassign_seat(env, group_id, group, from, to);
+
+        Self::remove_user_group(env, from, group_id);
+        let mut user_groups: Vec<String> = env
+            .storage().persistent().get(&DataKey::UserGroups(to.clone()))
+            .unwrap_or(Vec::new(env));
+        user_groups.push_back(group_id.clone());
+        env.storage().persistent().set(&DataKey::UserGroups(to.clone()), &user_groups);
+
+        sync_registry_member(env, group_id, from, false);
+        sync_registry_member(env, group_id, to, true);
+    }
+
+    /// Move everything a seat holds besides its `Member` record from `from`
+    /// to `to`: per-member balances and flags, history and votes.
+    fn reassign_seat(env: &Env, group_id: &String, group: &SavingsGroup, from: &Address, to: &Address) {
+        let storage = env.storage().persistent();
+        Self::move_entry::<i128>(
+            env,
+            DataKey::Collateral(group_id.clone(), from.clone()),
+            DataKey::Collateral(group_id.clone(), to.clone()),
+        );
+        Self::move_entry::<i128>(
+            env,
+            DataKey::ReservePaid(group_id.clone(), from.clone()),
+            DataKey::ReservePaid(group_id.clone(), to.clone()),
+        );
+        Self::move_entry::<i128>(
+            env,
+            DataKey::Dividends(group_id.clone(), from.clone()),
+            DataKey::Dividends(group_id.clone(), to.clone()),
+        );
+        Self::move_entry::<BytesN<32>>(
+            env,
+            DataKey::BidCommit(group_id.clone(), group.current_round, from.clone()),
+            DataKey::BidCommit(group_id.clone(), group.current_round, to.clone()),
+        );
+        let offer_key = DataKey::SwapOffer(group_id.clone(), from.clone());
+        if let Some(mut offer) = storage.get::<DataKey, SwapOffer>(&offer_key) {
+            storage.remove(&offer_key);
+            offer.proposer = to.clone();
+            storage.set(&DataKey::SwapOffer(group_id.clone(), to.clone()), &offer);
+        }
+        let top_bid_key = DataKey::TopBid(group_id.clone(), group.current_round);
+        if let Some(mut bid) = storage.get::<DataKey, Bid>(&top_bid_key) {
+            if bid.bidder == *from {
+                bid.bidder = to.clone();
+                storage.set(&top_bid_key, &bid);
+            }
+        }
+
+        for round in 1..=group.current_round {
+            Self::move_entry::<bool>(
+                env,
+                DataKey::Refunded(group_id.clone(), round, from.clone()),
+                DataKey::Refunded(group_id.clone(), round, to.clone()),
+            );
+            let key = DataKey::Contributions(group_id.clone(), round);
+            if let Some(contributions) = storage.get::<DataKey, Vec<Contribution>>(&key) {
+                let mut moved = Vec::new(env);
+                for mut c in contributions.iter() {
+                    if c.member == *from {
+                        c.member = to.clone();
+                    }
+                    moved.push_back(c);
+                }
+                storage.set(&key, &moved);
+            }
+            let key = DataKey::Payouts(group_id.clone(), round);
+            if let Some(payouts) = storage.get::<DataKey, Vec<Payout>>(&key) {
+                let mut moved = Vec::new(env);
+                for mut p in payouts.iter() {
+                    if p.recipient == *from {
+                        p.recipient = to.clone();
+                    }
+                    moved.push_back(p);
+                }
+                storage.set(&key, &moved);
+            }
+        }
+
+        for key in [DataKey::CancelVotes(group_id.clone()), DataKey::OrderApprovals(group_id.clone())] {
+            if let Some(mut votes) = storage.get::<DataKey, Vec<Address>>(&key) {
+                if let Some(idx) = votes.first_index_of(from) {
+                    votes.set(idx, to.clone());
+                    storage.set(&key, &votes);
+                }
+            }
+        }
+        let key = DataKey::Extension(group_id.clone());
+        if let Some(mut proposal) = storage.get::<DataKey, ExtensionProposal>(&key) {
+            if let Some(idx) = proposal.votes.first_index_of(from) {
+                proposal.votes.set(idx, to.clone());
+                storage.set(&key, &proposal);
+            }
+        }
+    }
+
+    fn move_entry<V>(env: &Env, from: DataKey, to: DataKey)
+    where
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        if let Some(value) = env.storage().persistent().get::<DataKey, V>(&from) {
+            env.storage().persistent().remove(&from);
+            env.storage().persistent().set(&to, &value);
+            env.storage().persistent().extend_ttl(&to, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+        }
+    }
+
+    fn remove_user_group(env: &Env, user: &Address, group_id: &String) {
+        let mut user_groups: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserGroups(user.clone()))
+            .unwrap_or(Vec::new(env));
+        if let Some(idx) = user_groups.first_index_of(group_id) {
+            user_groups.remove(idx);
+            env.storage()
+                .persistent()
+                .set(&DataKey::UserGroups(user.clone()), &user_groups);
+        }
+    }
+
+    /// A member's net position in a group: what they contributed (net of
+    /// refunds) minus the payout they received, floored at zero.
+    fn settlement_claim(env: &Env, group_id: &String, member: &Address) -> Result<i128, Error> {
+        let member_data: Member = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::MemberDataMissing)?;
+
+        let mut received: i128 = 0;
+        if member_data.has_received_payout {
+            let payouts: Vec<Payout> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Payouts(group_id.clone(), member_data.payout_round))
+                .unwrap_or(Vec::new(env));
+            for payout in payouts.iter() {
+                if payout.recipient == *member {
+                    received = received
+                        .checked_add(payout.amount)
+                        .ok_or(Error::ArithmeticOverflow)?;
+                }
+            }
+        }
+
+        let dividends: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dividends(group_id.clone(), member.clone()))
+            .unwrap_or(0);
+        received = received
+            .checked_add(dividends)
+            .ok_or(Error::ArithmeticOverflow)?;
+
+        let net = member_data
+            .total_contributed
+            .checked_sub(received)
+            .ok_or(Error::ArithmeticOverflow)?;
+        Ok(if net > 0 { net } else { 0 })
+    }
+
+    /// Load an Active auction group for a bid by `member`, returning the
+    /// group, its auction settings and the current round's deadline.
+    fn require_bidder(
+        env: &Env,
+        group_id: &String,
+        member: &Address,
+    ) -> Result<(SavingsGroup, AuctionConfig, u64), Error> {
+        let group: SavingsGroup = env
+            .storage().persistent().get(&DataKey::Group(group_id.clone()))
+            .ok_or(Error::GroupNotFound)?;
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+        let config = match &group.payout_mode {
+            PayoutMode::Auction(config) => config.clone(),
+            _ => return Err(Error::InvalidBid),
+        };
+
+        let member_data: Member = env
+            .storage().persistent().get(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .ok_or(Error::NotMember)?;
+        if member_data.has_received_payout || member_data.status == MemberStatus::Defaulted {
+            return Err(Error::InvalidBid);
+        }
+
+        let deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), group.current_round))
+            .unwrap_or(0);
+        Ok((group, config, deadline))
+    }
+
+    /// Move `amount` of collateral from `member` into custody.
+    fn post_collateral(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        member: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if amount <= 0 {
+            return Ok(());
+        }
+        token::Client::new(env, &group_token(env, group)?).transfer(
+            member,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let key = DataKey::Collateral(group_id.clone(), member.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let balance = balance.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
+        env.storage().persistent().set(&key, &balance);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+
+        events::CollateralPosted {
+            group_id: group_id.clone(),
+            member: member.clone(),
+            balance,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Pay all of `member`'s remaining collateral to `to`: the member
+    /// themselves, or the treasury when it is forfeited.
+    fn release_collateral(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        member: &Address,
+        to: &Address,
+    ) -> Result<(), Error> {
+        let key = DataKey::Collateral(group_id.clone(), member.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if balance <= 0 {
+            return Ok(());
+        }
+        env.storage().persistent().remove(&key);
+        token::Client::new(env, &group_token(env, group)?).transfer(
+            &env.current_contract_address(),
+            to,
+            &balance,
+        );
+
+        events::CollateralReleased {
+            group_id: group_id.clone(),
+            member: member.clone(),
+            to: to.clone(),
+            amount: balance,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Cover a defaulted member's current-round contribution out of their
+    /// collateral, recording it as a `Contribution` so the round's pot
+    /// includes it. Only whole contributions are slashed; returns whether
+    /// one was, and the caller saves `member_data`.
+    fn slash_collateral(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        member: &Address,
+        member_data: &mut Member,
+    ) -> Result<bool, Error> {
+        let key = DataKey::Collateral(group_id.clone(), member.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let amount = group.contribution_amount;
+        if balance < amount {
+            return Ok(false);
+        }
+        env.storage().persistent().set(&key, &(balance - amount));
+        adjust_escrow(env, group_id, amount)?;
+
+        let round = group.current_round;
+        let mut round_contributions: Vec<Contribution> = env
+            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), round))
+            .unwrap_or(Vec::new(env));
+        round_contributions.push_back(Contribution {
+            member: member.clone(),
+            amount,
+            round,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&DataKey::Contributions(group_id.clone(), round), &round_contributions);
+
+        member_data.total_contributed = member_data
+            .total_contributed
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+
+        events::CollateralSlashed {
+            group_id: group_id.clone(),
+            member: member.clone(),
+            amount,
+            round,
+        }
+        .publish(env);
+        Ok(true)
+    }
+
+    /// Cover the current-round contributions of defaulted members who have
+    /// not paid it: out of their collateral when there is enough left, and
+    /// otherwise out of the reserve under `DefaultPolicy::Reserve`.
+    /// Collateral is recorded as the defaulter's contribution; the reserve
+    /// covers what the defaulter's contribution would have added to the pot
+    /// and is noted so a cure repays it. Returns the amount drawn from the
+    /// reserve, which is added to the pool and the escrow.
+    fn cover_defaults(env: &Env, group_id: &String, group: &SavingsGroup) -> Result<i128, Error> {
+        if group.collateral_amount <= 0 && group.default_policy != DefaultPolicy::Reserve {
+            return Ok(0);
+        }
+
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut reserve: i128 = env
+            .storage().persistent().get(&DataKey::Reserve(group_id.clone()))
+            .unwrap_or(0);
+        let mut covered: i128 = 0;
+        let share = group.contribution_amount - Self::reserve_cut(group);
+
+        for member_addr in members.iter() {
+            let key = DataKey::MemberData(group_id.clone(), member_addr.clone());
+            let mut member_data: Member = match env.storage().persistent().get(&key) {
+                Some(data) => data,
+                None => continue,
+            };
+            if member_data.status != MemberStatus::Defaulted
+                || Self::has_contributed(env, group_id, group.current_round, &member_addr)
+            {
+                continue;
+            }
+
+            if Self::slash_collateral(env, group_id, group, &member_addr, &mut member_data)? {
+                env.storage().persistent().set(&key, &member_data);
+            } else if group.default_policy == DefaultPolicy::Reserve && share > 0 && reserve >= share {
+                reserve -= share;
+                covered = covered.checked_add(share).ok_or(Error::ArithmeticOverflow)?;
+                let cover_key = DataKey::ReserveCover(group_id.clone(), group.current_round, member_addr.clone());
+                env.storage().persistent().set(&cover_key, &true);
+                env.storage().persistent().extend_ttl(&cover_key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+                events::DefaultCovered {
+                    group_id: group_id.clone(),
+                    member: member_addr,
+                    amount: share,
+                    round: group.current_round,
+                }
+                .publish(env);
+            }
+        }
+
+        if covered > 0 {
+            env.storage().persistent().set(&DataKey::Reserve(group_id.clone()), &reserve);
+            adjust_escrow(env, group_id, covered)?;
+        }
+        Ok(covered)
+    }
+
+    /// Under `DefaultPolicy::TopUp`, what each member in good standing pays
+    /// on top of `contribution_amount` to make up for defaulted members who
+    /// have not paid the current round and have too little collateral left to
+    /// cover it. Division dust is not collected.
+    fn top_up_share(env: &Env, group_id: &String, group: &SavingsGroup) -> Result<i128, Error> {
+        if group.default_policy != DefaultPolicy::TopUp {
+            return Ok(0);
+        }
+
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut missing: i128 = 0;
+        let mut payers: i128 = 0;
+        for member_addr in members.iter() {
+            if let Some(data) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+            {
+                let collateral: i128 = env
+                    .storage().persistent().get(&DataKey::Collateral(group_id.clone(), member_addr.clone()))
+                    .unwrap_or(0);
+                if data.status != MemberStatus::Defaulted {
+                    payers += 1;
+                } else if !Self::has_contributed(env, group_id, group.current_round, &member_addr)
+                    && collateral < group.contribution_amount
+                {
+                    missing += 1;
+                }
+            }
+        }
+        if missing == 0 || payers == 0 {
+            return Ok(0);
+        }
+
+        Ok(group
+            .contribution_amount
+            .checked_mul(missing)
+            .ok_or(Error::ArithmeticOverflow)?
+            / payers)
+    }
+
+    /// Distribute the current round once every member has paid or defaulted
+    /// and, for auctions, bidding has closed. A round whose scheduled
+    /// recipient has defaulted is left for `force_end_round`.
+    fn distribute_if_settled(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        keeper: Option<Address>,
+    ) -> Result<(), Error> {
+        if !Self::all_members_paid(env, group_id.clone(), group.current_round)
+            || !Self::bidding_closed(env, group_id, group)
+        {
+            return Ok(());
+        }
+        match Self::distribute_payout(env, group_id.clone(), keeper) {
+            Err(Error::NoRecipientFound) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Apply an extension proposal once a strict majority of members backs it.
+    fn tally_extension(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        proposal: ExtensionProposal,
+    ) -> Result<(), Error> {
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        if proposal.votes.len() * 2 <= members.len() {
+            return Ok(());
+        }
+
+        env.storage().persistent().remove(&DataKey::Extension(group_id.clone()));
+        Self::shift_deadlines(env, group_id, group, proposal.seconds)?;
+
+        events::DeadlineExtended {
+            group_id: group_id.clone(),
+            round: proposal.round,
+            seconds: proposal.seconds,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Push the current round's deadline and every later one back by `seconds`.
+    fn shift_deadlines(env: &Env, group_id: &String, group: &SavingsGroup, seconds: u64) -> Result<(), Error> {
+        if seconds == 0 {
+            return Ok(());
+        }
+        let shift_key = DataKey::DeadlineShift(group_id.clone());
+        let shift: u64 = env.storage().persistent().get(&shift_key).unwrap_or(0);
+        let shift = shift.checked_add(seconds).ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&shift_key, &shift);
+        env.storage().persistent().extend_ttl(&shift_key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+
+        let deadline_key = DataKey::RoundDeadline(group_id.clone(), group.current_round);
+        if let Some(deadline) = env.storage().persistent().get::<DataKey, u64>(&deadline_key) {
+            let deadline = deadline.checked_add(seconds).ok_or(Error::Overflow)?;
+            env.storage().persistent().set(&deadline_key, &deadline);
+        }
+        Ok(())
+    }
+
+    /// Rejects an auction bid window or commit-reveal window that is zero or
+    /// longer than `grace_period`.
+    fn check_payout_mode(mode: &PayoutMode, grace_period: u64) -> Result<(), Error> {
+        match mode {
+            // Sealed reveals must finish before a stalled round can be force-ended.
+            PayoutMode::Auction(config)
+                if config.bid_window == 0 || config.bid_window > grace_period =>
+            {
+                Err(Error::InvalidConfig)
+            }
+            PayoutMode::CommitReveal(window) if *window == 0 || *window > grace_period => {
+                Err(Error::InvalidConfig)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Rejects a grace period longer than `MAX_GRACE_PERIOD_SECONDS` or
+    /// shorter than the payout mode's bid or reveal window.
+    fn check_grace_period(mode: &PayoutMode, seconds: u64) -> Result<(), Error> {
+        // Sealed reveals and seed reveals must still fit inside the grace period.
+        let window = match mode {
+            PayoutMode::Auction(config) => config.bid_window,
+            PayoutMode::CommitReveal(window) => *window,
+            _ => 0,
+        };
+        if seconds > MAX_GRACE_PERIOD_SECONDS || seconds < window {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Rejects a negative collateral amount, or one below a single contribution.
+    fn check_collateral(amount: i128, contribution_amount: i128) -> Result<(), Error> {
+        // Collateral is slashed one whole contribution at a time.
+        if amount < 0 || (amount > 0 && amount < contribution_amount) {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Rejects a `MemberQuorum` that is zero or not below `total_members`.
+    fn check_join_policy(policy: &JoinPolicy, total_members: u32) -> Result<(), Error> {
+        if let JoinPolicy::MemberQuorum(quorum) = policy {
+            if *quorum == 0 || *quorum >= total_members {
+                return Err(Error::InvalidConfig);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a negative flat late fee or a bps late fee above 10,000.
+    fn check_late_fee(config: &LateFeeConfig) -> Result<(), Error> {
+        match config.fee {
+            LateFee::Flat(amount) if amount < 0 => Err(Error::InvalidConfig),
+            LateFee::Bps(bps) if bps > BPS_DENOMINATOR => Err(Error::InvalidConfig),
+            _ => Ok(()),
+        }
+    }
+
+    /// Charge `member` the group's late fee for contributing after
+    /// `deadline`, paying it straight to its destination. Returns the amount
+    /// charged.
+    fn charge_late_fee(
+        env: &Env,
+        group_id: &String,
+        group: &SavingsGroup,
+        member: &Address,
+        deadline: u64,
+    ) -> Result<i128, Error> {
+        let config: LateFeeConfig = match env.storage().persistent().get(&DataKey::LateFee(group_id.clone())) {
+            Some(config) => config,
+            None => return Ok(0),
+        };
+
+        let base = match config.fee {
+            LateFee::Flat(amount) => amount,
+            LateFee::Bps(bps) => (group.contribution_amount * (bps as i128)) / (BPS_DENOMINATOR as i128),
+        };
+        let mut fee = base;
+        if config.escalating {
+            let days_late = (env.ledger().timestamp() - deadline).div_ceil(86_400);
+            fee = base.checked_mul(days_late as i128).ok_or(Error::ArithmeticOverflow)?;
+        }
+        if fee <= 0 {
+            return Ok(0);
+        }
+
+        let token_client = token::Client::new(env, &group_token(env, group)?);
+        match config.destination {
+            LateFeeDestination::Reserve => {
+                token_client.transfer(member, env.current_contract_address(), &fee);
+                let reserve: i128 = env
+                    .storage().persistent().get(&DataKey::Reserve(group_id.clone()))
+                    .unwrap_or(0);
+                let reserve = reserve.checked_add(fee).ok_or(Error::ArithmeticOverflow)?;
+                env.storage().persistent().set(&DataKey::Reserve(group_id.clone()), &reserve);
+            }
+            LateFeeDestination::Treasury => {
+                token_client.transfer(member, &group.treasury, &fee);
+                let collected: i128 = env
+                    .storage().persistent().get(&DataKey::FeesCollected(group_id.clone()))
+                    .unwrap_or(0);
+                let collected = collected.checked_add(fee).ok_or(Error::ArithmeticOverflow)?;
+                env.storage().persistent().set(&DataKey::FeesCollected(group_id.clone()), &collected);
+            }
+            LateFeeDestination::Members => {
+                let members: Vec<Address> = env
+                    .storage().persistent().get(&DataKey::Members(group_id.clone()))
+                    .unwrap_or(Vec::new(env));
+                let mut recipients: Vec<Address> = Vec::new(env);
+                for member_addr in members.iter() {
+                    if member_addr == *member {
+                        continue;
+                    }
+                    if let Some(data) = env
+                        .storage()
+                        .persistent()
+                        .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+                    {
+                        if data.status != MemberStatus::Defaulted {
+                            recipients.push_back(member_addr);
+                        }
+                    }
+                }
+                if recipients.is_empty() {
+                    return Ok(0);
+                }
+                let share = fee / (recipients.len() as i128);
+                for recipient in recipients.iter() {
+                    if share > 0 {
+                        token_client.transfer(member, &recipient, &share);
+                    }
+                }
+                fee = share * (recipients.len() as i128);
+            }
+        }
+        Ok(fee)
+    }
+
+    /// The part of `contribution_amount` set aside in the reserve.
+    fn reserve_cut(group: &SavingsGroup) -> i128 {
+        (group.contribution_amount * (group.reserve_bps as i128)) / (BPS_DENOMINATOR as i128)
+    }
 
-        Self::end_round(env, group_id, group)?;
+    /// Credit `amount` paid by `member` to the group's reserve.
+    fn add_to_reserve(env: &Env, group_id: &String, member: &Address, amount: i128) -> Result<(), Error> {
+        for key in [
+            DataKey::Reserve(group_id.clone()),
+            DataKey::ReservePaid(group_id.clone(), member.clone()),
+        ] {
+            let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            let balance = balance.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
+            env.storage().persistent().set(&key, &balance);
+            env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
+        }
+        Ok(())
+    }
 
+    /// Add `amount` already in custody to the group's reserve without
+    /// crediting any member's share of it.
+    fn credit_reserve(env: &Env, group_id: &String, amount: i128) -> Result<(), Error> {
+        let key = DataKey::Reserve(group_id.clone());
+        let reserve: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let reserve = reserve.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
+        env.storage().persistent().set(&key, &reserve);
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
         Ok(())
     }
 
-    // #637: This function performs O(n) storage reads and writes per round close
-    // (one per member) to reset PaidCurrentRound back to Active. For a 20-member
-    // group this means up to 40 storage operations every round. A future
-    // optimization could track "paid this round" via a per-round Vec/Set rather
-    // than a persistent per-member status flag, eliminating the reset loop.
-    fn end_round(env: &Env, group_id: String, mut group: SavingsGroup) -> Result<(), Error> {
+    /// Each member's claim on the reserve: members in good standing share it
+    /// in proportion to what they paid in, or equally if none of them paid
+    /// in (the reserve then holds only forfeited collateral or late
+    /// payments); defaulted members get nothing. Division dust goes to the
+    /// last member with a claim.
+    fn reserve_claims(env: &Env, group_id: &String) -> Result<Vec<(Address, i128)>, Error> {
+        let reserve: i128 = env
+            .storage().persistent().get(&DataKey::Reserve(group_id.clone()))
+            .unwrap_or(0);
         let members: Vec<Address> = env
             .storage().persistent().get(&DataKey::Members(group_id.clone()))
-            .unwrap_or(Vec::new(&env));
+            .unwrap_or(Vec::new(env));
 
+        let mut paid: Vec<(Address, i128)> = Vec::new(env);
+        let mut good_standing: Vec<(Address, i128)> = Vec::new(env);
+        let mut total_paid: i128 = 0;
         for member_addr in members.iter() {
-            if let Some(mut member_data) = env
+            let defaulted = env
                 .storage()
                 .persistent()
                 .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
-            {
-                if member_data.status == MemberStatus::PaidCurrentRound {
-                    member_data.status = MemberStatus::Active;
-                }
-                // Keep Defaulted and ReceivedPayout as is
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::MemberData(group_id.clone(), member_addr.clone()), &member_data);
+                .map(|data| data.status == MemberStatus::Defaulted)
+                .unwrap_or(true);
+            let share: i128 = env
+                .storage().persistent().get(&DataKey::ReservePaid(group_id.clone(), member_addr.clone()))
+                .unwrap_or(0);
+            if !defaulted {
+                good_standing.push_back((member_addr.clone(), 1));
             }
+            if !defaulted && share > 0 {
+                total_paid = total_paid.checked_add(share).ok_or(Error::ArithmeticOverflow)?;
+                paid.push_back((member_addr, share));
+            }
+        }
+        if paid.is_empty() {
+            total_paid = good_standing.len() as i128;
+            paid = good_standing;
         }
 
-        if group.current_round >= group.total_members {
-            group.status = GroupStatus::Completed;
-        } else {
-            group.current_round += 1;
-            let deadline = Self::calculate_deadline(env, &group, group.current_round);
-            env.storage().persistent().set(&DataKey::RoundDeadline(group_id.clone(), group.current_round), &deadline);
+        let mut claims: Vec<(Address, i128)> = Vec::new(env);
+        let mut remaining = reserve;
+        for (i, (member_addr, share)) in paid.iter().enumerate() {
+            let claim = if i as u32 + 1 == paid.len() {
+                remaining
+            } else {
+                reserve.checked_mul(share).ok_or(Error::ArithmeticOverflow)? / total_paid
+            };
+            remaining -= claim;
+            claims.push_back((member_addr, claim));
         }
+        Ok(claims)
+    }
 
-        env.storage().persistent().set(&DataKey::Group(group_id), &group);
+    /// Split what is left of the reserve among members in good standing when
+    /// a group completes. With no such member it goes to the treasury.
+    fn settle_reserve(env: &Env, group_id: &String, group: &SavingsGroup) -> Result<(), Error> {
+        let reserve: i128 = env
+            .storage().persistent().get(&DataKey::Reserve(group_id.clone()))
+            .unwrap_or(0);
+        if reserve <= 0 {
+            return Ok(());
+        }
+        let mut claims = Self::reserve_claims(env, group_id)?;
+        env.storage().persistent().remove(&DataKey::Reserve(group_id.clone()));
+        if claims.is_empty() {
+            claims.push_back((group.treasury.clone(), reserve));
+        }
 
-        // #636: Use saturating_sub to guard against underflow if current_round is ever 0.
-        // Currently unreachable (current_round starts at 1), but protects against
-        // future changes to round-numbering logic.
-        let ended_round = group.current_round.saturating_sub(1);
-        env.events()
-            .publish((symbol_short!("round_end"),), ended_round);
+        let token_client = token::Client::new(env, &group_token(env, group)?);
+        for (to, amount) in claims.iter() {
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &to, &amount);
+                events::ReserveReleased { group_id: group_id.clone(), to, amount }.publish(env);
+            }
+        }
+        Ok(())
+    }
 
+    /// Return collateral to members in good standing when a group completes;
+    /// defaulted members' remaining collateral goes to the treasury.
+    fn settle_collateral(env: &Env, group_id: &String, group: &SavingsGroup) -> Result<(), Error> {
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        for member_addr in members.iter() {
+            let defaulted = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+                .map(|data| data.status == MemberStatus::Defaulted)
+                .unwrap_or(false);
+            let to = if defaulted { group.treasury.clone() } else { member_addr.clone() };
+            Self::release_collateral(env, group_id, group, &member_addr, &to)?;
+        }
         Ok(())
     }
 
-    /// Internal: create and persist a new member record, push to the members
-    /// vec, and increment the member count. Returns the new member count.
-    fn add_member_to_group(
+    /// Check that two payout positions of an Active, positional group can
+    /// still be traded: distinct, in range, and neither paid out yet.
+    fn require_swappable(
         env: &Env,
-        member: &Address,
         group_id: &String,
-    ) -> u32 {
-        let member_count: u32 = env
-            .storage().persistent().get(&DataKey::MemberCount(group_id.clone()))
-            .unwrap_or(0);
+        group: &SavingsGroup,
+        my_round: u32,
+        their_round: u32,
+    ) -> Result<(), Error> {
+        if group.status != GroupStatus::Active {
+            return Err(Error::GroupNotActive);
+        }
+        if matches!(group.payout_mode, PayoutMode::Auction(_)) || my_round == their_round {
+            return Err(Error::InvalidSwap);
+        }
+        for round in [my_round, their_round] {
+            if round < group.current_round || round > group.total_members {
+                return Err(Error::InvalidRound);
+            }
+            let payouts: Vec<Payout> = env
+                .storage().persistent().get(&DataKey::Payouts(group_id.clone(), round))
+                .unwrap_or(Vec::new(env));
+            if !payouts.is_empty() {
+                return Err(Error::InvalidRound);
+            }
+        }
+        Ok(())
+    }
 
-        let new_member = Member {
-            address: member.clone(),
-            join_timestamp: env.ledger().timestamp(),
-            join_order: member_count,
-            status: MemberStatus::Active,
-            total_contributed: 0,
-            has_received_payout: false,
-            payout_round: 0,
-        };
+    /// The unpaid member scheduled to receive `round`'s payout, if any.
+    fn round_holder(env: &Env, group_id: &String, group: &SavingsGroup, round: u32) -> Option<Address> {
+        let join_order = group.payout_order.get(round.checked_sub(1)?)?;
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        for member_addr in members.iter() {
+            if let Some(data) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+            {
+                if data.join_order == join_order && !data.has_received_payout {
+                    return Some(member_addr);
+                }
+            }
+        }
+        None
+    }
 
-        env.storage().persistent().set(&DataKey::MemberData(group_id.clone(), member.clone()), &new_member);
+    /// Whether the ledger time is within `[open, close)`.
+    fn in_window(env: &Env, open: u64, close: u64) -> bool {
+        let now = env.ledger().timestamp();
+        now >= open && now < close
+    }
 
-        let mut members: Vec<Address> = env
-            .storage().persistent().get(&DataKey::Members(group_id.clone()))
-            .unwrap_or(Vec::new(&env));
-        members.push_back(member.clone());
-        env.storage().persistent().set(&DataKey::Members(group_id.clone()), &members);
+    fn record_bid(
+        env: &Env,
+        group_id: &String,
+        round: u32,
+        member: &Address,
+        discount_bps: u32,
+    ) -> Result<(), Error> {
+        if discount_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidBid);
+        }
+        let key = DataKey::TopBid(group_id.clone(), round);
+        if let Some(top) = env.storage().persistent().get::<DataKey, Bid>(&key) {
+            if discount_bps <= top.discount_bps {
+                return Err(Error::InvalidBid);
+            }
+        }
+        env.storage().persistent().set(&key, &Bid { bidder: member.clone(), discount_bps });
+        env.storage().persistent().extend_ttl(&key, GROUP_TTL_EXTEND, GROUP_TTL_EXTEND);
 
-        let new_count = member_count + 1;
-        env.storage()
-            .persistent()
-            .set(&DataKey::MemberCount(group_id.clone()), &new_count);
+        events::BidPlaced {
+            group_id: group_id.clone(),
+            round,
+            bidder: member.clone(),
+            discount_bps,
+        }
+        .publish(env);
+        Ok(())
+    }
 
-        new_count
+    /// Whether the current round's payout may be distributed: always for
+    /// shuffled groups, and once bidding (including sealed reveals) has
+    /// closed for auction groups.
+    fn bidding_closed(env: &Env, group_id: &String, group: &SavingsGroup) -> bool {
+        let config = match &group.payout_mode {
+            PayoutMode::Auction(config) => config,
+            _ => return true,
+        };
+        let deadline: u64 = env
+            .storage().persistent().get(&DataKey::RoundDeadline(group_id.clone(), group.current_round))
+            .unwrap_or(0);
+        let close = if config.sealed {
+            deadline.saturating_add(config.bid_window)
+        } else {
+            deadline
+        };
+        env.ledger().timestamp() >= close
     }
 
-    fn add_admin_to_group(env: &Env, member: Address, group_id: String) -> Result<(), Error> {
-        // Guard: admin must not already be a member of this group
-        if env
+    /// The current round's auction winner and discount: the top bidder if
+    /// still eligible, otherwise the earliest unpaid, non-defaulted member in
+    /// `payout_order` with no discount.
+    fn auction_winner(env: &Env, group_id: &String, group: &SavingsGroup) -> Result<(Address, u32), Error> {
+        if let Some(top) = env
             .storage()
             .persistent()
-            .has(&DataKey::MemberData(group_id.clone(), member.clone()))
+            .get::<DataKey, Bid>(&DataKey::TopBid(group_id.clone(), group.current_round))
         {
-            return Err(Error::AlreadyMember);
+            if let Some(data) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), top.bidder.clone()))
+            {
+                if !data.has_received_payout && data.status != MemberStatus::Defaulted {
+                    return Ok((top.bidder, top.discount_bps));
+                }
+            }
         }
 
-        let new_count = Self::add_member_to_group(env, &member, &group_id);
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(env));
+        for join_order in group.payout_order.iter() {
+            for member_addr in members.iter() {
+                if let Some(data) = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr.clone()))
+                {
+                    if data.join_order == join_order
+                        && !data.has_received_payout
+                        && data.status != MemberStatus::Defaulted
+                    {
+                        return Ok((member_addr, 0));
+                    }
+                }
+            }
+        }
+        Err(Error::NoRecipientFound)
+    }
 
-        env.events()
-            .publish((symbol_short!("joined"),), (member.clone(), new_count));
+    fn keeper_config(env: &Env, group_id: &String) -> KeeperConfig {
+        env.storage()
+            .persistent()
+            .get(&DataKey::KeeperConfig(group_id.clone()))
+            .unwrap_or(KeeperConfig {
+                restricted: false,
+                keepers: Vec::new(env),
+                bounty_bps: 0,
+            })
+    }
 
-        // #750: include group_id in the admin's joined event
-        env.events().publish(
-            (symbol_short!("joined"),),
-            (group_id.clone(), member, new_count),
-        );
-        Ok(())
+    /// Authenticate a keeper call and enforce the group's keeper restriction.
+    fn require_keeper(env: &Env, group: &SavingsGroup, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+
+        let config = Self::keeper_config(env, &group.group_id);
+        if !config.restricted
+            || *caller == group.admin
+            || config.keepers.contains(caller)
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::MemberData(group.group_id.clone(), caller.clone()))
+        {
+            return Ok(());
+        }
+        Err(Error::NotKeeper)
     }
 
-    fn calculate_deadline(_env: &Env, group: &SavingsGroup, round: u32) -> u64 {
-        let round_duration = match group.frequency {
-            Frequency::Weekly => 604800,
-            Frequency::BiWeekly => 1209600,
-            Frequency::Monthly => 2592000,
-        };
-        group.start_timestamp + (round as u64 * round_duration)
+    fn has_contributed(env: &Env, group_id: &String, round: u32, member: &Address) -> bool {
+        let contributions: Vec<Contribution> = env
+            .storage().persistent().get(&DataKey::Contributions(group_id.clone(), round))
+            .unwrap_or(Vec::new(env));
+        contributions.iter().any(|c| c.member == *member)
     }
 
+    /// Whether every member has contributed to `round` or defaulted, so the
+    /// round can close without waiting on anyone.
     fn all_members_paid(env: &Env, group_id: String, round: u32) -> bool {
         let members: Vec<Address> = env
             .storage().persistent().get(&DataKey::Members(group_id.clone()))
             .unwrap_or(Vec::new(&env));
 
-        let contributions: Vec<Contribution> = env
-            .storage().persistent().get(&DataKey::Contributions(group_id, round))
-            .unwrap_or(Vec::new(&env));
-
-        contributions.len() == members.len()
+        for member_addr in members.iter() {
+            if Self::has_contributed(env, &group_id, round, &member_addr) {
+                continue;
+            }
+            let defaulted = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Member>(&DataKey::MemberData(group_id.clone(), member_addr))
+                .map(|data| data.status == MemberStatus::Defaulted)
+                .unwrap_or(false);
+            if !defaulted {
+                return false;
+            }
+        }
+        true
     }
 
     /// Select the payout recipient for the given round using the
@@ -1457,6 +5663,144 @@ impl SavingsContract {
         }
     }
 
+    /// Returns the funds the contract currently holds for a group's rounds.
+    pub fn get_escrow(env: Env, group_id: String) -> i128 {
+        env.storage().persistent().get(&DataKey::Escrow(group_id))
+            .unwrap_or(0)
+    }
+
+    /// Returns the members who have voted to cancel a group.
+    pub fn get_cancel_votes(env: Env, group_id: String) -> Vec<Address> {
+        env.storage().persistent().get(&DataKey::CancelVotes(group_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns the settlement snapshot of a group cancelled by member vote.
+    pub fn get_settlement(env: Env, group_id: String) -> Option<Settlement> {
+        env.storage().persistent().get(&DataKey::Settlement(group_id))
+    }
+
+    /// Returns a group's keeper configuration (unrestricted, no bounty by default).
+    pub fn get_keeper_config(env: Env, group_id: String) -> KeeperConfig {
+        Self::keeper_config(&env, &group_id)
+    }
+
+    /// Returns the entropy transcript behind a CommitReveal group's payout order.
+    pub fn get_order_transcript(env: Env, group_id: String) -> Option<OrderTranscript> {
+        env.storage().persistent().get(&DataKey::OrderTranscript(group_id))
+    }
+
+    /// Returns when a CommitReveal group's reveal window closes, once started.
+    pub fn get_reveal_deadline(env: Env, group_id: String) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::RevealDeadline(group_id))
+    }
+
+    /// Returns the payout order proposed for a `PayoutMode::Fixed` group, if any.
+    pub fn get_proposed_order(env: Env, group_id: String) -> Option<Vec<u32>> {
+        env.storage().persistent().get(&DataKey::ProposedOrder(group_id))
+    }
+
+    /// Returns the members who approved a Fixed group's proposed payout order.
+    pub fn get_order_approvals(env: Env, group_id: String) -> Vec<Address> {
+        env.storage().persistent().get(&DataKey::OrderApprovals(group_id.clone()))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns the collateral a member currently has escrowed in a group.
+    pub fn get_collateral(env: Env, group_id: String, member: Address) -> i128 {
+        env.storage().persistent().get(&DataKey::Collateral(group_id, member)).unwrap_or(0)
+    }
+
+    /// Returns the total collateral escrowed by a group's members.
+    pub fn get_group_collateral(env: Env, group_id: String) -> i128 {
+        let members: Vec<Address> = env
+            .storage().persistent().get(&DataKey::Members(group_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut total: i128 = 0;
+        for member_addr in members.iter() {
+            total += Self::get_collateral(env.clone(), group_id.clone(), member_addr);
+        }
+        total
+    }
+
+    /// Returns the group's deadline extension proposal, if one is open.
+    pub fn get_extension_proposal(env: Env, group_id: String) -> Option<ExtensionProposal> {
+        env.storage().persistent().get(&DataKey::Extension(group_id))
+    }
+
+    /// Returns a group's late fee configuration, if any.
+    pub fn get_late_fee(env: Env, group_id: String) -> Option<LateFeeConfig> {
+        env.storage().persistent().get(&DataKey::LateFee(group_id))
+    }
+
+    /// Returns a group's insurance reserve balance.
+    pub fn get_reserve(env: Env, group_id: String) -> i128 {
+        env.storage().persistent().get(&DataKey::Reserve(group_id)).unwrap_or(0)
+    }
+
+    /// Returns what a member would receive from the reserve if the group
+    /// completed now: zero while they are defaulted.
+    pub fn get_reserve_claim(env: Env, group_id: String, member: Address) -> Result<i128, Error> {
+        for (addr, claim) in Self::reserve_claims(&env, &group_id)?.iter() {
+            if addr == member {
+                return Ok(claim);
+            }
+        }
+        Ok(0)
+    }
+
+    /// Returns a member's open payout-position swap offer, if any.
+    pub fn get_swap_offer(env: Env, group_id: String, proposer: Address) -> Option<SwapOffer> {
+        env.storage().persistent().get(&DataKey::SwapOffer(group_id, proposer))
+    }
+
+    /// Returns the leading bid for a round of an auction group, if any.
+    pub fn get_top_bid(env: Env, group_id: String, round: u32) -> Option<Bid> {
+        env.storage().persistent().get(&DataKey::TopBid(group_id, round))
+    }
+
+    /// Returns the auction discounts a member has received as dividends.
+    pub fn get_dividends(env: Env, group_id: String, member: Address) -> i128 {
+        env.storage().persistent().get(&DataKey::Dividends(group_id, member))
+            .unwrap_or(0)
+    }
+
+    /// Returns the total platform fees a group has sent to its treasury.
+    pub fn get_fees_collected(env: Env, group_id: String) -> i128 {
+        env.storage().persistent().get(&DataKey::FeesCollected(group_id))
+            .unwrap_or(0)
+    }
+
+    /// Returns whether a member has already claimed a refund for a round.
+    pub fn is_refunded(env: Env, group_id: String, round: u32, member: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Refunded(group_id, round, member))
+    }
+
+    /// Returns the platform fee (in basis points) applied to new groups.
+    pub fn get_default_fee(env: Env) -> u32 {
+        Self::default_fee(&env)
+    }
+
+    /// Returns the cap (in basis points) on any group's platform fee.
+    pub fn get_max_fee(env: Env) -> u32 {
+        Self::max_fee(&env)
+    }
+
+    /// Returns the contract version whose schema the stored records follow.
+    pub fn get_schema_version(env: Env) -> String {
+        Self::schema_version(&env)
+    }
+
+    /// Returns the `GroupRegistry` the contract keeps in sync, if configured.
+    pub fn get_registry(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Registry)
+    }
+
+    /// Returns the native XLM Stellar Asset Contract configured at `initialize`.
+    pub fn get_native_token(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::NativeToken)
+    }
+
     /// Retrieves a savings group by its ID.
     ///
     /// # Errors
@@ -1,15 +1,39 @@
-use crate::{Error, Frequency, GroupStatus, MemberStatus, SavingsContract, SavingsContractClient};
+use crate::{
+    AuctionConfig, DataKey, DefaultPolicy, Error, Frequency, GroupOptions, GroupStatus, JoinPolicy,
+    KeeperConfig, LateFee, LateFeeConfig, LateFeeDestination, LegacySavingsGroup, MemberStatus,
+    PayoutMode, SavingsContract, SavingsContractClient, SavingsGroup, DEFAULT_MAX_PLATFORM_FEE_BPS,
+    MAX_WAITLIST,
+};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    Address, Env, String,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, BytesN as _, Ledger},
+    token, Address, Bytes, BytesN, Env, String, Vec,
 };
 
+/// Register the savings contract and initialize it with a freshly registered
+/// Stellar Asset Contract standing in for native XLM.
+fn register_savings(env: &Env) -> SavingsContractClient<'_> {
+    let contract_id = env.register(SavingsContract, ());
+    let client = SavingsContractClient::new(env, &contract_id);
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client.initialize(&Address::generate(env), &native.address());
+    client
+}
+
+/// Mint enough native XLM to every member of a group to cover all rounds.
+fn fund_members(env: &Env, client: &SavingsContractClient<'_>, group_id: &String) {
+    let native = client.get_native_token().unwrap();
+    let sac = token::StellarAssetClient::new(env, &native);
+    for member in client.get_members(group_id).iter() {
+        sac.mint(&member, &10_000_000_000);
+    }
+}
+
 fn create_test_group(env: &Env) -> (Address, SavingsContractClient<'_>) {
     env.ledger().with_mut(|li| {
         li.timestamp = 100_000;
     });
-    let contract_id = env.register(SavingsContract, ());
-    let client = SavingsContractClient::new(env, &contract_id);
+    let client = register_savings(env);
     let admin = Address::generate(env);
     (admin, client)
 }
@@ -36,6 +60,7 @@ fn setup_full_group(env: &Env) -> (Address, Address, Address, Address, SavingsCo
     let m2 = Address::generate(env);
     client.join_group(&m1, &group_id);
     client.join_group(&m2, &group_id);
+    fund_members(env, &client, &group_id);
 
     assert_eq!(client.get_group(&group_id).status, GroupStatus::Active);
     (admin, m1, m2, Address::generate(env), client, group_id)
@@ -110,7 +135,6 @@ fn test_join_group() {
 }
 
 #[test]
-#[should_panic]
 fn test_cannot_join_full_group() {
     let env = Env::default();
     env.mock_all_auths();
@@ -129,7 +153,18 @@ fn test_cannot_join_full_group() {
     let m3 = Address::generate(&env);
     client.join_group(&m1, &group_id);
     client.join_group(&m2, &group_id);
+
+    // Later joiners are waitlisted until the waitlist is full too.
     client.join_group(&m3, &group_id);
+    assert!(client.try_get_member(&m3, &group_id).is_err());
+    assert_eq!(client.get_waitlist_position(&group_id, &m3), Some(1));
+    for _ in 1..MAX_WAITLIST {
+        client.join_group(&Address::generate(&env), &group_id);
+    }
+    assert_eq!(
+        client.try_join_group(&Address::generate(&env), &group_id).err(),
+        Some(Ok(Error::GroupIsFull))
+    );
 }
 
 #[test]
@@ -193,6 +228,7 @@ fn test_payout_order_is_not_deterministic() {
     let m2 = Address::generate(&env);
     client.join_group(&m1, &group_id);
     client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
 
     let group = client.get_group(&group_id);
 
@@ -262,8 +298,7 @@ fn test_get_round_deadline() {
         li.timestamp = 100_000;
     });
 
-    let contract_id = env.register(SavingsContract, ());
-    let client = SavingsContractClient::new(&env, &contract_id);
+    let client = register_savings(&env);
     let admin = Address::generate(&env);
     let group_id = String::from_str(&env, "test-group");
     let name = String::from_str(&env, "Test Group");
@@ -299,6 +334,75 @@ fn test_cancel_group_success() {
     assert_eq!(client.get_all_groups().len(), 1);
     client.cancel_group(&admin, &group_id);
     assert_eq!(client.get_all_groups().len(), 0);
+    assert_eq!(client.get_user_groups(&admin).len(), 0);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Cancelled);
+}
+
+#[test]
+fn test_vote_cancel_settles_net_positions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, m1, m2, keeper, client, group_id) = setup_full_group(&env);
+    let group = client.get_group(&group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+    let members = [admin.clone(), m1.clone(), m2.clone()];
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 1;
+    });
+    for member in members.iter() {
+        client.contribute(member, &group_id);
+    }
+    let first = client.get_round_payouts(&group_id, &1).get(0).unwrap().recipient;
+
+    // Round 2: only the two members still waiting for a payout pay in.
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 604800 + 1;
+    });
+    for member in members.iter() {
+        if *member != first {
+            client.contribute(member, &group_id);
+        }
+    }
+    assert_eq!(client.get_escrow(&group_id), 200_000_000);
+
+    // An outsider cannot vote, and one vote of three is not a majority.
+    assert_eq!(
+        client.try_vote_cancel(&keeper, &group_id).err(),
+        Some(Ok(Error::NotMember))
+    );
+    let voters: [Address; 2] = if first == admin {
+        [m1.clone(), m2.clone()]
+    } else {
+        [admin.clone(), first.clone()]
+    };
+    client.vote_cancel(&voters[0], &group_id);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Active);
+    client.vote_cancel(&voters[1], &group_id);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Cancelled);
+    assert_eq!(client.get_cancel_votes(&group_id).len(), 2);
+
+    // The two unpaid members are each owed 200M, but only 200M is in escrow,
+    // so both are repaid pro rata; the paid-out member is owed nothing.
+    let settlement = client.get_settlement(&group_id).unwrap();
+    assert_eq!(settlement.pot, 200_000_000);
+    assert_eq!(settlement.total_claims, 400_000_000);
+
+    let before: [i128; 3] = [xlm.balance(&admin), xlm.balance(&m1), xlm.balance(&m2)];
+    assert_eq!(client.settle_cancelled(&keeper, &group_id, &2), 1);
+    assert_eq!(client.settle_cancelled(&keeper, &group_id, &2), 0);
+    assert_eq!(
+        client.try_settle_cancelled(&keeper, &group_id, &2).err(),
+        Some(Ok(Error::NoRefundAvailable))
+    );
+
+    for (i, member) in members.iter().enumerate() {
+        let expected = if *member == first { 0 } else { 100_000_000 };
+        assert_eq!(xlm.balance(member) - before[i], expected);
+    }
+    assert_eq!(xlm.balance(&client.address), 0);
+    assert_eq!(client.get_escrow(&group_id), 0);
 }
 
 #[test]
@@ -389,12 +493,12 @@ fn test_retry_distribution() {
     assert_eq!(payouts.len(), 1);
 
     // Round already completed via auto-distribution; retry returns an error
-    let result = client.try_retry_distribution(&group_id);
+    let result = client.try_retry_distribution(&admin, &group_id);
     assert_eq!(result, Err(Ok(Error::NotAllPaid)));
 }
 
 #[test]
-fn test_claim_refund() {
+fn test_claim_refund_unavailable_after_payout() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -412,19 +516,68 @@ fn test_claim_refund() {
     let payouts = client.get_round_payouts(&group_id, &1);
     assert_eq!(payouts.len(), 1);
 
-    let recipient = payouts.get(0).unwrap().recipient.clone();
-
-    // Two non-recipients should be able to claim refunds
-    let mut refunded_count = 0u32;
+    // The round's pool went to its recipient, so nobody can claw it back.
     for member in [admin.clone(), m1.clone(), m2.clone()].iter() {
-        if *member != recipient {
-            let result = client.try_claim_refund(member, &group_id, &1);
-            if result == Ok(Ok(100_000_000)) {
-                refunded_count += 1;
-            }
+        let result = client.try_claim_refund(member, &group_id, &1);
+        assert_eq!(result.err(), Some(Ok(Error::NoRefundAvailable)));
+    }
+}
+
+#[test]
+fn test_claim_refund_after_stalled_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, _, keeper, client, group_id) = setup_full_group(&env);
+    let group = client.get_group(&group_id);
+    let members = client.get_members(&group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    // Everyone except the scheduled round-1 recipient pays.
+    let recipient = members.get(group.payout_order.get(0).unwrap()).unwrap();
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 1;
+    });
+    for member in members.iter() {
+        if member != recipient {
+            client.contribute(&member, &group_id);
+        }
+    }
+
+    // Refunds are not available while the round is still open.
+    let payer = members.iter().find(|m| *m != recipient).unwrap();
+    assert_eq!(
+        client.try_claim_refund(&payer, &group_id, &1).err(),
+        Some(Ok(Error::NoRefundAvailable))
+    );
+
+    // The recipient defaults, so the stalled round closes without a payout.
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
+    });
+    client.force_end_round(&keeper, &group_id);
+    assert_eq!(client.get_round_payouts(&group_id, &1).len(), 0);
+
+    for member in members.iter() {
+        if member == recipient {
+            assert_eq!(
+                client.try_claim_refund(&member, &group_id, &1).err(),
+                Some(Ok(Error::NoRefundAvailable))
+            );
+        } else {
+            assert_eq!(client.claim_refund(&member, &group_id, &1), 100_000_000);
+            assert_eq!(xlm.balance(&member), 10_000_000_000);
+            assert!(client.is_refunded(&group_id, &1, &member));
+            assert_eq!(client.get_member(&member, &group_id).total_contributed, 0);
         }
     }
-    assert_eq!(refunded_count, 2);
+    assert_eq!(xlm.balance(&client.address), 0);
+
+    // A refund can only be claimed once.
+    assert_eq!(
+        client.try_claim_refund(&payer, &group_id, &1).err(),
+        Some(Ok(Error::NoRefundAvailable))
+    );
 }
 
 #[test]
@@ -526,7 +679,7 @@ fn test_force_end_round() {
         li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
     });
 
-    client.force_end_round(&group_id);
+    client.force_end_round(&admin, &group_id);
 
     let group = client.get_group(&group_id);
     assert!(group.current_round > 1 || group.status == GroupStatus::Completed);
@@ -538,7 +691,9 @@ fn test_initialize_sets_admin() {
     let contract_id = env.register(SavingsContract, ());
     let client = SavingsContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let native = env.register_stellar_asset_contract_v2(admin.clone());
+    client.initialize(&admin, &native.address());
+    assert_eq!(client.get_native_token(), Some(native.address()));
 }
 
 #[test]
@@ -548,8 +703,9 @@ fn test_initialize_cannot_be_called_twice() {
     let contract_id = env.register(SavingsContract, ());
     let client = SavingsContractClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
-    client.initialize(&admin);
-    client.initialize(&admin);
+    let native = env.register_stellar_asset_contract_v2(admin.clone());
+    client.initialize(&admin, &native.address());
+    client.initialize(&admin, &native.address());
 }
 
 #[test]
@@ -564,7 +720,7 @@ fn test_mark_defaulted_external() {
         li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
     });
 
-    client.mark_defaulted(&m2, &group_id);
+    client.mark_defaulted(&admin, &m2, &group_id);
     let member_data = client.get_member(&m2, &group_id);
     assert_eq!(member_data.status, MemberStatus::Defaulted);
 }
@@ -577,8 +733,7 @@ fn test_get_user_groups() {
         li.timestamp = 100_000;
     });
 
-    let contract_id = env.register(SavingsContract, ());
-    let client = SavingsContractClient::new(&env, &contract_id);
+    let client = register_savings(&env);
     let user = Address::generate(&env);
     let group_id = String::from_str(&env, "test-group");
     let name = String::from_str(&env, "Test Group");
@@ -604,8 +759,7 @@ fn test_get_all_groups() {
         li.timestamp = 100_000;
     });
 
-    let contract_id = env.register(SavingsContract, ());
-    let client = SavingsContractClient::new(&env, &contract_id);
+    let client = register_savings(&env);
     let all_groups = client.get_all_groups();
     assert_eq!(all_groups.len(), 0);
 
@@ -630,8 +784,7 @@ fn test_paginated_groups() {
         li.timestamp = 100_000;
     });
 
-    let contract_id = env.register(SavingsContract, ());
-    let client = SavingsContractClient::new(&env, &contract_id);
+    let client = register_savings(&env);
     let admin = Address::generate(&env);
     let name = String::from_str(&env, "Test Group");
 
@@ -690,6 +843,7 @@ fn test_admin_not_guaranteed_first_payout() {
     let m2 = Address::generate(&env);
     client.join_group(&m1, &group_id);
     client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
 
     let group = client.get_group(&group_id);
 
@@ -800,7 +954,7 @@ fn test_defaulted_member_cannot_contribute() {
         li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
     });
 
-    client.mark_defaulted(&m2, &group_id);
+    client.mark_defaulted(&admin, &m2, &group_id);
 
     // Next round
     let group = client.get_group(&group_id);
@@ -826,7 +980,7 @@ fn test_cure_default_allows_rejoin() {
         li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
     });
 
-    client.force_end_round(&group_id);
+    client.force_end_round(&admin, &group_id);
 
     // Resume the group so cure_default can work
     client.resume_group(&admin, &group_id);
@@ -836,6 +990,127 @@ fn test_cure_default_allows_rejoin() {
 
     let member_data = client.get_member(&m2, &group_id);
     assert_eq!(member_data.status, MemberStatus::Active);
+    assert_eq!(member_data.total_contributed, 100_000_000);
+
+    // The catch-up for round 1 was actually collected and recorded.
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+    assert_eq!(xlm.balance(&m2), 10_000_000_000 - 100_000_000);
+    let round_one = client.get_round_contributions(&group_id, &1);
+    assert_eq!(round_one.len(), 1);
+    assert_eq!(round_one.get(0).unwrap().member, m2);
+
+    // A catch-up payment is not refundable.
+    assert_eq!(
+        client.try_claim_refund(&m2, &group_id, &1).err(),
+        Some(Ok(Error::NoRefundAvailable))
+    );
+
+    // In a group with a late fee, each missed round is also charged the fee.
+    let fee_admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_group = String::from_str(&env, "late-cure");
+    client.create_group(
+        &fee_admin, &fee_group, &String::from_str(&env, "Late Cure"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+    client.set_late_fee(&fee_admin, &fee_group, &Some(LateFeeConfig {
+        fee: LateFee::Flat(5_000_000),
+        escalating: false,
+        destination: LateFeeDestination::Treasury,
+    }));
+    let late = Address::generate(&env);
+    client.join_group(&Address::generate(&env), &fee_group);
+    client.join_group(&late, &fee_group);
+    fund_members(&env, &client, &fee_group);
+
+    let deadline = client.get_round_deadline(&fee_group, &1);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259200 + 1);
+    client.force_end_round(&fee_admin, &fee_group);
+    client.resume_group(&fee_admin, &fee_group);
+
+    let before = xlm.balance(&late);
+    client.cure_default(&late, &fee_group);
+    assert_eq!(xlm.balance(&late), before - 100_000_000 - 5_000_000);
+    assert_eq!(xlm.balance(&treasury), 5_000_000);
+    assert_eq!(client.get_member(&late, &fee_group).status, MemberStatus::Active);
+}
+
+#[test]
+fn test_cure_default_after_receiving_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, m1, m2, _, client, group_id) = setup_full_group(&env);
+    let group = client.get_group(&group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 1;
+    });
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+    let first = client.get_round_payouts(&group_id, &1).get(0).unwrap().recipient;
+
+    // Round 2: the round-1 recipient stops paying and is defaulted.
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 604800 + 1;
+    });
+    for member in [admin.clone(), m1.clone(), m2.clone()].iter() {
+        if *member != first {
+            client.contribute(member, &group_id);
+        }
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 2 * 604800 + 259200 + 1;
+    });
+    client.mark_defaulted(&admin, &first, &group_id);
+    assert_eq!(client.get_member(&first, &group_id).status, MemberStatus::Defaulted);
+
+    // Only round 2 is owed; paying it completes the round and releases the payout.
+    client.cure_default(&first, &group_id);
+
+    let member_data = client.get_member(&first, &group_id);
+    assert_eq!(member_data.total_contributed, 200_000_000);
+    assert_eq!(xlm.balance(&first), 10_000_000_000 - 200_000_000 + 294_000_000);
+    assert_eq!(client.get_round_payouts(&group_id, &2).len(), 1);
+    assert_eq!(client.get_group(&group_id).current_round, 3);
+}
+
+#[test]
+fn test_cured_default_pays_out_late_rounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, m1, m2, _, client, group_id) = setup_full_group(&env);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+    let start = client.get_group(&group_id).start_timestamp;
+
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+
+    // m2 misses round 2, which closes with the smaller pot.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    let short_paid = client.get_round_payouts(&group_id, &2).get(0).unwrap().recipient;
+    let before = xlm.balance(&short_paid);
+
+    // The late round-2 payment goes to round 2's recipient, not into escrow.
+    client.cure_default(&m2, &group_id);
+    assert_eq!(xlm.balance(&short_paid), before + 100_000_000);
+    assert_eq!(client.get_round_contributions(&group_id, &2).len(), 3);
+
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+    assert_eq!(xlm.balance(&client.address), 0);
 }
 
 #[test]
@@ -867,8 +1142,7 @@ fn test_get_user_groups_page() {
         li.timestamp = 100_000;
     });
 
-    let contract_id = env.register(SavingsContract, ());
-    let client = SavingsContractClient::new(&env, &contract_id);
+    let client = register_savings(&env);
     let user = Address::generate(&env);
     let name = String::from_str(&env, "Grp");
 
@@ -897,3 +1171,1992 @@ fn test_get_user_groups_page() {
     let page = client.get_user_groups_page(&user, &0, &2);
     assert_eq!(page.len(), 2);
 }
+
+// ─── Platform fee configuration tests ───────────────────────────────
+
+#[test]
+fn test_contract_admin_sets_default_and_max_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 100_000;
+    });
+
+    let contract_id = env.register(SavingsContract, ());
+    let client = SavingsContractClient::new(&env, &contract_id);
+    let platform = Address::generate(&env);
+    let native = env.register_stellar_asset_contract_v2(platform.clone());
+    client.initialize(&platform, &native.address());
+
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_set_max_fee(&outsider, &500).err(), Some(Ok(Error::NotAdmin)));
+    assert_eq!(client.try_set_default_fee(&outsider, &100).err(), Some(Ok(Error::NotAdmin)));
+
+    client.set_max_fee(&platform, &500);
+    assert_eq!(client.try_set_default_fee(&platform, &600).err(), Some(Ok(Error::InvalidConfig)));
+    client.set_default_fee(&platform, &300);
+    assert_eq!(client.try_set_max_fee(&platform, &250).err(), Some(Ok(Error::InvalidConfig)));
+    assert_eq!(client.get_default_fee(), 300);
+    assert_eq!(client.get_max_fee(), 500);
+
+    let admin = Address::generate(&env);
+    let group = client.create_group(
+        &admin, &String::from_str(&env, "dflt-fee"), &String::from_str(&env, "Grp"),
+        &100_000_000, &3, &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    assert_eq!(group.platform_fee_percent, 300);
+}
+
+#[test]
+fn test_fee_free_group_and_fee_locked_once_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let group_id = String::from_str(&env, "fee-free");
+
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Community"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+
+    assert_eq!(
+        client.try_set_group_fee(&admin, &group_id, &(DEFAULT_MAX_PLATFORM_FEE_BPS + 1)).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_group_fee(&treasury, &group_id, &0).err(),
+        Some(Ok(Error::AdminOnly))
+    );
+    client.set_group_fee(&admin, &group_id, &0);
+
+    // Once someone else has joined, the fee they joined under is fixed.
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    assert_eq!(
+        client.try_set_group_fee(&admin, &group_id, &100).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
+
+    assert_eq!(
+        client.try_set_group_fee(&admin, &group_id, &100).err(),
+        Some(Ok(Error::GroupNotOpen))
+    );
+
+    let group = client.get_group(&group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 1;
+    });
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+
+    assert_eq!(client.get_round_payouts(&group_id, &1).get(0).unwrap().amount, 300_000_000);
+    assert_eq!(client.get_fees_collected(&group_id), 0);
+}
+
+#[test]
+fn test_create_group_applies_options() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "with-options");
+    let name = String::from_str(&env, "Options");
+    let start = env.ledger().timestamp() + 100;
+    let native = client.get_native_token().unwrap();
+    token::StellarAssetClient::new(&env, &native).mint(&admin, &1_000_000_000);
+
+    let late_fee = LateFeeConfig {
+        fee: LateFee::Flat(1_000_000),
+        escalating: false,
+        destination: LateFeeDestination::Treasury,
+    };
+    let mut options = GroupOptions {
+        fee_bps: Some(0),
+        payout_mode: PayoutMode::Fixed,
+        collateral_amount: 200_000_000,
+        default_policy: DefaultPolicy::Collateral,
+        reserve_bps: 500,
+        grace_period: 86_400,
+        join_policy: JoinPolicy::AdminApproval,
+        late_fee: late_fee.clone(),
+        ..GroupOptions::default()
+    };
+
+    // Options go through the same checks as their setters.
+    for invalid in [
+        GroupOptions { fee_bps: Some(DEFAULT_MAX_PLATFORM_FEE_BPS + 1), ..options.clone() },
+        GroupOptions { payout_mode: PayoutMode::CommitReveal(86_401), ..options.clone() },
+        GroupOptions { collateral_amount: 1, ..options.clone() },
+        GroupOptions { join_policy: JoinPolicy::MemberQuorum(3), ..options.clone() },
+    ] {
+        assert_eq!(
+            client
+                .try_create_group(
+                    &admin, &group_id, &name, &100_000_000, &3, &Frequency::Weekly, &start,
+                    &true, &admin, &Some(invalid),
+                )
+                .err(),
+            Some(Ok(Error::InvalidConfig))
+        );
+    }
+
+    options.token_address = Some(native.clone());
+    let group = client.create_group(
+        &admin, &group_id, &name, &100_000_000, &3, &Frequency::Weekly, &start,
+        &true, &admin, &Some(options),
+    );
+    assert_eq!(group.platform_fee_percent, 0);
+    assert_eq!(group.token_address, Some(native.clone()));
+    assert_eq!(group.payout_mode, PayoutMode::Fixed);
+    assert_eq!(group.collateral_amount, 200_000_000);
+    assert_eq!(group.default_policy, DefaultPolicy::Collateral);
+    assert_eq!(group.reserve_bps, 500);
+    assert_eq!(group.grace_period, 86_400);
+    assert_eq!(group.join_policy, JoinPolicy::AdminApproval);
+    assert_eq!(client.get_late_fee(&group_id), Some(late_fee));
+
+    // The admin's collateral is escrowed on creation.
+    assert_eq!(client.get_collateral(&group_id, &admin), 200_000_000);
+    assert_eq!(token::Client::new(&env, &native).balance(&admin), 800_000_000);
+}
+
+// ─── Keeper tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_restricted_keepers_reject_outsiders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, m1, m2, outsider, client, group_id) = setup_full_group(&env);
+    let keeper = Address::generate(&env);
+    let group = client.get_group(&group_id);
+
+    client.set_keeper_config(
+        &admin,
+        &group_id,
+        &KeeperConfig {
+            restricted: true,
+            keepers: soroban_sdk::vec![&env, keeper.clone()],
+            bounty_bps: 0,
+        },
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
+    });
+
+    assert_eq!(
+        client.try_mark_defaulted(&outsider, &m2, &group_id).err(),
+        Some(Ok(Error::NotKeeper))
+    );
+    assert_eq!(
+        client.try_force_end_round(&outsider, &group_id).err(),
+        Some(Ok(Error::NotKeeper))
+    );
+
+    // Members and whitelisted keepers may act.
+    client.mark_defaulted(&m1, &m2, &group_id);
+    assert_eq!(client.get_member(&m2, &group_id).status, MemberStatus::Defaulted);
+    client.force_end_round(&keeper, &group_id);
+}
+
+#[test]
+fn test_keeper_bounty_paid_from_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let group_id = String::from_str(&env, "bounty-group");
+
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Bounty"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+    client.join_group(&Address::generate(&env), &group_id);
+    client.join_group(&Address::generate(&env), &group_id);
+    fund_members(&env, &client, &group_id);
+
+    assert_eq!(
+        client.try_set_keeper_config(
+            &admin,
+            &group_id,
+            &KeeperConfig { restricted: false, keepers: soroban_sdk::vec![&env], bounty_bps: 10_001 },
+        ).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.set_keeper_config(
+        &admin,
+        &group_id,
+        &KeeperConfig { restricted: false, keepers: soroban_sdk::vec![&env], bounty_bps: 2_500 },
+    );
+
+    // Everyone but one non-recipient pays, then a keeper closes the round.
+    let group = client.get_group(&group_id);
+    let members = client.get_members(&group_id);
+    let recipient = members.get(group.payout_order.get(0).unwrap()).unwrap();
+    let skipper = members.iter().find(|m| *m != recipient).unwrap();
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 1;
+    });
+    for member in members.iter() {
+        if member != skipper {
+            client.contribute(&member, &group_id);
+        }
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 604800 + 259200 + 1;
+    });
+    client.force_end_round(&keeper, &group_id);
+
+    // Pool 200M, fee 4M, a quarter of which goes to the keeper.
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+    assert_eq!(xlm.balance(&keeper), 1_000_000);
+    assert_eq!(xlm.balance(&treasury), 3_000_000);
+    assert_eq!(client.get_fees_collected(&group_id), 3_000_000);
+}
+
+// ─── Native XLM custody tests ───────────────────────────────────────
+
+#[test]
+fn test_create_native_group_requires_native_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 100_000;
+    });
+
+    let contract_id = env.register(SavingsContract, ());
+    let client = SavingsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    let result = client.try_create_group(
+        &admin, &String::from_str(&env, "no-native"), &String::from_str(&env, "Grp"),
+        &100_000_000, &3, &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    assert_eq!(result.err(), Some(Ok(Error::NativeTokenNotSet)));
+}
+
+#[test]
+fn test_native_contributions_are_escrowed_and_paid_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, m1, m2, _, client, group_id) = setup_full_group(&env);
+    let group = client.get_group(&group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 1;
+    });
+
+    client.contribute(&admin, &group_id);
+    assert_eq!(xlm.balance(&client.address), 100_000_000);
+    assert_eq!(xlm.balance(&admin), 10_000_000_000 - 100_000_000);
+
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+
+    let payout = client.get_round_payouts(&group_id, &1).get(0).unwrap();
+    assert_eq!(
+        xlm.balance(&payout.recipient),
+        10_000_000_000 - 100_000_000 + payout.amount
+    );
+    // Nothing is left in custody once the round is paid out and the fee sent.
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_platform_fee_sent_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let group_id = String::from_str(&env, "fee-group");
+
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Fee Group"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
+
+    let group = client.get_group(&group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+    assert_eq!(client.get_fees_collected(&group_id), 0);
+
+    for round in 1..=2u32 {
+        env.ledger().with_mut(|li| {
+            li.timestamp = group.start_timestamp + (round as u64 * 604800) + 1;
+        });
+        client.contribute(&admin, &group_id);
+        client.contribute(&m1, &group_id);
+        client.contribute(&m2, &group_id);
+    }
+
+    // 2% of a 300M pool, twice.
+    assert_eq!(xlm.balance(&treasury), 12_000_000);
+    assert_eq!(client.get_fees_collected(&group_id), 12_000_000);
+}
+
+// ─── Upgrade & migration tests ──────────────────────────────────────
+
+#[test]
+fn test_upgrade_requires_contract_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = register_savings(&env);
+
+    let outsider = Address::generate(&env);
+    let hash = BytesN::<32>::random(&env);
+    assert_eq!(client.try_upgrade(&outsider, &hash).err(), Some(Ok(Error::NotAdmin)));
+    assert_eq!(client.try_migrate(&outsider, &10).err(), Some(Ok(Error::NotAdmin)));
+}
+
+#[test]
+fn test_migrate_backfills_legacy_payout_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 100_000;
+    });
+
+    let contract_id = env.register(SavingsContract, ());
+    let client = SavingsContractClient::new(&env, &contract_id);
+    let platform = Address::generate(&env);
+    let native = env.register_stellar_asset_contract_v2(platform.clone());
+    client.initialize(&platform, &native.address());
+    assert_eq!(client.get_schema_version(), String::from_str(&env, "0.3.0"));
+
+    let admin = Address::generate(&env);
+    let group_id = String::from_str(&env, "legacy-group");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Legacy"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
+
+    let group = client.get_group(&group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp = group.start_timestamp + 604800 + 1;
+    });
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+    let first_recipient = client.get_round_payouts(&group_id, &1).get(0).unwrap().recipient;
+
+    // Rewind storage to what a 0.1.0 deployment left behind.
+    env.as_contract(&contract_id, || {
+        let key = DataKey::Group(group_id.clone());
+        let group: SavingsGroup = env.storage().persistent().get(&key).unwrap();
+        let legacy = LegacySavingsGroup {
+            group_id: group.group_id,
+            admin: group.admin,
+            name: group.name,
+            contribution_amount: group.contribution_amount,
+            total_members: group.total_members,
+            frequency: group.frequency,
+            start_timestamp: group.start_timestamp,
+            status: group.status,
+            is_public: group.is_public,
+            current_round: group.current_round,
+            platform_fee_percent: group.platform_fee_percent,
+            treasury: group.treasury,
+            token_address: group.token_address,
+            payout_order: Vec::new(&env),
+        };
+        env.storage().persistent().set(&key, &legacy);
+        env.storage().persistent().remove(&DataKey::Version);
+    });
+    assert_eq!(client.get_schema_version(), String::from_str(&env, "0.1.0"));
+
+    assert_eq!(client.migrate(&platform, &10), 0);
+    assert_eq!(client.get_schema_version(), String::from_str(&env, "0.3.0"));
+
+    let migrated = client.get_group(&group_id);
+    assert_eq!(migrated.payout_mode, PayoutMode::Shuffled);
+    let order = migrated.payout_order;
+    assert_eq!(order.len(), 3);
+    assert_eq!(order.get(0).unwrap(), client.get_member(&first_recipient, &group_id).join_order);
+    for jo in 0..3u32 {
+        assert!(order.contains(jo));
+    }
+
+    // Already on the current schema: nothing left to do.
+    assert_eq!(client.migrate(&platform, &10), 0);
+}
+
+// ─── Registry sync tests ────────────────────────────────────────────
+
+/// Stand-in for `GroupRegistry` that records what the savings contract pushes.
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn sync_member(
+        env: Env,
+        contract_address: Address,
+        _group_id: String,
+        member: Address,
+        joined: bool,
+        member_count: u32,
+    ) {
+        contract_address.require_auth();
+        let mut members: Vec<Address> = env
+            .storage().instance().get(&symbol_short!("members"))
+            .unwrap_or(Vec::new(&env));
+        if joined {
+            members.push_back(member);
+        } else if let Some(idx) = members.first_index_of(&member) {
+            members.remove(idx);
+        }
+        env.storage().instance().set(&symbol_short!("members"), &members);
+        env.storage().instance().set(&symbol_short!("count"), &member_count);
+    }
+
+    pub fn sync_admin(env: Env, contract_address: Address, _group_id: String, new_admin: Address) {
+        contract_address.require_auth();
+        env.storage().instance().set(&symbol_short!("admin"), &new_admin);
+    }
+
+    pub fn sync_closed(env: Env, contract_address: Address, _group_id: String, cancelled: bool) {
+        contract_address.require_auth();
+        env.storage().instance().set(&symbol_short!("closed"), &cancelled);
+        let closes: u32 = env.storage().instance().get(&symbol_short!("closes")).unwrap_or(0);
+        env.storage().instance().set(&symbol_short!("closes"), &(closes + 1));
+    }
+
+    pub fn members(env: Env) -> Vec<Address> {
+        env.storage().instance().get(&symbol_short!("members")).unwrap_or(Vec::new(&env))
+    }
+
+    pub fn count(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("count")).unwrap_or(0)
+    }
+
+    pub fn admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("admin"))
+    }
+
+    pub fn closed(env: Env) -> Option<bool> {
+        env.storage().instance().get(&symbol_short!("closed"))
+    }
+
+    pub fn closes(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("closes")).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_registry_kept_in_sync() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 100_000;
+    });
+
+    let contract_id = env.register(SavingsContract, ());
+    let client = SavingsContractClient::new(&env, &contract_id);
+    let platform = Address::generate(&env);
+    let native = env.register_stellar_asset_contract_v2(platform.clone());
+    client.initialize(&platform, &native.address());
+
+    let registry_id = env.register(MockRegistry, ());
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_registry(&outsider, &Some(registry_id.clone())).err(),
+        Some(Ok(Error::NotAdmin))
+    );
+    client.set_registry(&platform, &Some(registry_id.clone()));
+    assert_eq!(client.get_registry(), Some(registry_id));
+
+    let admin = Address::generate(&env);
+    let group_id = String::from_str(&env, "synced");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Synced"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+
+    let m1 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    assert_eq!(registry.members(), Vec::from_array(&env, [m1.clone()]));
+    assert_eq!(registry.count(), 2);
+
+    client.remove_member(&admin, &group_id, &m1);
+    assert_eq!(registry.members().len(), 0);
+    assert_eq!(registry.count(), 1);
+
+    let new_admin = Address::generate(&env);
+    client.transfer_admin(&group_id, &admin, &new_admin);
+    assert_eq!(registry.admin(), Some(new_admin.clone()));
+
+    client.cancel_group(&new_admin, &group_id);
+    assert_eq!(registry.closed(), Some(true));
+}
+
+#[test]
+fn test_force_ending_last_round_completes_group_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 100_000;
+    });
+
+    let contract_id = env.register(SavingsContract, ());
+    let client = SavingsContractClient::new(&env, &contract_id);
+    let platform = Address::generate(&env);
+    let native = env.register_stellar_asset_contract_v2(platform.clone());
+    client.initialize(&platform, &native.address());
+    let registry_id = env.register(MockRegistry, ());
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    client.set_registry(&platform, &Some(registry_id));
+
+    let admin = Address::generate(&env);
+    let group_id = String::from_str(&env, "force-last");
+    let group = client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Force Last"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    let members = [admin.clone(), Address::generate(&env), Address::generate(&env)];
+    client.join_group(&members[1], &group_id);
+    client.join_group(&members[2], &group_id);
+    fund_members(&env, &client, &group_id);
+
+    for round in 1..=2u64 {
+        env.ledger().with_mut(|li| li.timestamp = group.start_timestamp + (round - 1) * 604800 + 1);
+        for member in members.iter() {
+            client.contribute(member, &group_id);
+        }
+    }
+
+    // Round 3: the last recipient pays but a paid-out member stalls the
+    // round, so it is force-ended with a payout.
+    env.ledger().with_mut(|li| li.timestamp = group.start_timestamp + 2 * 604800 + 1);
+    let staller = members
+        .iter()
+        .find(|member| client.get_member(member, &group_id).has_received_payout)
+        .unwrap();
+    for member in members.iter() {
+        if member != staller {
+            client.contribute(member, &group_id);
+        }
+    }
+    let deadline = client.get_round_deadline(&group_id, &3);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259200 + 1);
+    client.force_end_round(&admin, &group_id);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.status, GroupStatus::Completed);
+    assert_eq!(group.current_round, 3);
+    assert_eq!(client.get_round_payouts(&group_id, &3).len(), 1);
+    assert_eq!(registry.closes(), 1);
+}
+
+// ─── Auction tests ──────────────────────────────────────────────────
+
+/// Create a 3-member Weekly auction group with a 1-day bid window and fund
+/// its members. Returns (admin, m1, m2, client, group_id, round 1 deadline).
+fn setup_auction_group(
+    env: &Env,
+    sealed: bool,
+) -> (Address, Address, Address, SavingsContractClient<'_>, String, u64) {
+    let (admin, client) = create_test_group(env);
+    let group_id = String::from_str(env, "auction");
+    client.create_group(
+        &admin, &group_id, &String::from_str(env, "Auction"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &Address::generate(env), &None,
+    );
+    client.set_payout_mode(
+        &admin,
+        &group_id,
+        &PayoutMode::Auction(AuctionConfig { sealed, bid_window: 86_400 }),
+    );
+    let m1 = Address::generate(env);
+    let m2 = Address::generate(env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    fund_members(env, &client, &group_id);
+
+    let deadline = client.get_round_deadline(&group_id, &1);
+    (admin, m1, m2, client, group_id, deadline)
+}
+
+#[test]
+fn test_open_auction_pays_highest_bidder_and_shares_discount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, m1, m2, client, group_id, deadline) = setup_auction_group(&env, false);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    // Bidding opens one bid window before the deadline.
+    env.ledger().with_mut(|li| li.timestamp = deadline - 86_400 - 1);
+    assert_eq!(client.try_place_bid(&m1, &group_id, &500).err(), Some(Ok(Error::BiddingClosed)));
+
+    env.ledger().with_mut(|li| li.timestamp = deadline - 3_600);
+    client.place_bid(&m1, &group_id, &500);
+    assert_eq!(client.try_place_bid(&m2, &group_id, &500).err(), Some(Ok(Error::InvalidBid)));
+    client.place_bid(&m2, &group_id, &1_000);
+
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+
+    // Everyone paid, but the pot waits for bidding to close.
+    assert_eq!(client.get_round_payouts(&group_id, &1).len(), 0);
+    assert_eq!(client.try_retry_distribution(&admin, &group_id).err(), Some(Ok(Error::BiddingOpen)));
+
+    let admin_before = xlm.balance(&admin);
+    let m1_before = xlm.balance(&m1);
+    let m2_before = xlm.balance(&m2);
+    env.ledger().with_mut(|li| li.timestamp = deadline);
+    client.retry_distribution(&admin, &group_id);
+
+    // 300M pool, 2% fee -> 294M; 10% discount -> 29.4M shared by two members.
+    let payout = client.get_round_payouts(&group_id, &1).get(0).unwrap();
+    assert_eq!(payout.recipient, m2);
+    assert_eq!(payout.amount, 264_600_000);
+    assert_eq!(xlm.balance(&m2) - m2_before, 264_600_000);
+    assert_eq!(xlm.balance(&m1) - m1_before, 14_700_000);
+    assert_eq!(xlm.balance(&admin) - admin_before, 14_700_000);
+    assert_eq!(client.get_dividends(&group_id, &m1), 14_700_000);
+    assert_eq!(client.get_group(&group_id).current_round, 2);
+
+    // The winner cannot bid again.
+    let deadline2 = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline2 - 3_600);
+    assert_eq!(client.try_place_bid(&m2, &group_id, &100).err(), Some(Ok(Error::InvalidBid)));
+}
+
+#[test]
+fn test_sealed_auction_commit_and_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, m1, m2, client, group_id, deadline) = setup_auction_group(&env, true);
+
+    let commitment = |bps: u32, salt: &BytesN<32>| -> BytesN<32> {
+        let mut preimage = soroban_sdk::Bytes::from_array(&env, &bps.to_be_bytes());
+        preimage.append(&soroban_sdk::Bytes::from(salt.clone()));
+        env.crypto().sha256(&preimage).into()
+    };
+    let salt1 = BytesN::<32>::random(&env);
+    let salt2 = BytesN::<32>::random(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = deadline - 3_600);
+    assert_eq!(client.try_place_bid(&m1, &group_id, &500).err(), Some(Ok(Error::InvalidBid)));
+    client.commit_bid(&m1, &group_id, &commitment(800, &salt1));
+    client.commit_bid(&m2, &group_id, &commitment(300, &salt2));
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+
+    // Reveals only open at the deadline.
+    assert_eq!(client.try_reveal_bid(&m1, &group_id, &800, &salt1).err(), Some(Ok(Error::BiddingClosed)));
+    env.ledger().with_mut(|li| li.timestamp = deadline + 60);
+    assert_eq!(client.try_reveal_bid(&m1, &group_id, &900, &salt1).err(), Some(Ok(Error::InvalidBid)));
+    client.reveal_bid(&m2, &group_id, &300, &salt2);
+    client.reveal_bid(&m1, &group_id, &800, &salt1);
+    assert_eq!(client.get_top_bid(&group_id, &1).unwrap().bidder, m1);
+
+    assert_eq!(client.try_retry_distribution(&admin, &group_id).err(), Some(Ok(Error::BiddingOpen)));
+    env.ledger().with_mut(|li| li.timestamp = deadline + 86_400);
+    client.retry_distribution(&admin, &group_id);
+
+    let payout = client.get_round_payouts(&group_id, &1).get(0).unwrap();
+    assert_eq!(payout.recipient, m1);
+    // 294M net pool minus an 8% discount.
+    assert_eq!(payout.amount, 270_480_000);
+}
+
+// ─── Fixed payout order tests ───────────────────────────────────────
+
+#[test]
+fn test_fixed_payout_order_activates_after_majority_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "fixed");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Fixed"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+
+    let order = Vec::from_array(&env, [2u32, 0, 1]);
+    assert_eq!(
+        client.try_propose_payout_order(&admin, &group_id, &order).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    for bad in [
+        Vec::from_array(&env, [0u32, 1]),
+        Vec::from_array(&env, [0u32, 1, 1]),
+        Vec::from_array(&env, [0u32, 1, 3]),
+    ] {
+        assert_eq!(
+            client.try_propose_payout_order(&admin, &group_id, &bad).err(),
+            Some(Ok(Error::InvalidConfig))
+        );
+    }
+    client.propose_payout_order(&admin, &group_id, &order);
+
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+
+    // Full, but only the admin has approved: 1 of 3 is not a majority.
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Open);
+    assert_eq!(client.get_order_approvals(&group_id).len(), 1);
+
+    client.approve_payout_order(&m2, &group_id);
+    let group = client.get_group(&group_id);
+    assert_eq!(group.status, GroupStatus::Active);
+    assert_eq!(group.current_round, 1);
+    assert_eq!(group.payout_order, order);
+
+    // Round 1 goes to join_order 2 (m2).
+    fund_members(&env, &client, &group_id);
+    env.ledger().with_mut(|li| li.timestamp = group.start_timestamp + 604800 + 1);
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    client.contribute(&m2, &group_id);
+    assert_eq!(client.get_round_payouts(&group_id, &1).get(0).unwrap().recipient, m2);
+}
+
+// ─── Commit-reveal payout order tests ───────────────────────────────
+
+#[test]
+fn test_commit_reveal_order_is_recomputable_from_transcript() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "cr-group");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Commit Reveal"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 10_000),
+        &true, &admin, &None,
+    );
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::CommitReveal(3_600));
+
+    let hash = |bytes: &BytesN<32>| -> BytesN<32> {
+        env.crypto().sha256(&soroban_sdk::Bytes::from(bytes.clone())).into()
+    };
+    let admin_secret = BytesN::<32>::random(&env);
+    let m1_secret = BytesN::<32>::random(&env);
+
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m2_secret = BytesN::<32>::random(&env);
+    client.commit_seed(&admin, &group_id, &hash(&admin_secret));
+    client.join_group(&m1, &group_id);
+    client.commit_seed(&m1, &group_id, &hash(&m1_secret));
+    client.join_group(&m2, &group_id);
+    assert_eq!(client.get_reveal_deadline(&group_id), None);
+    client.commit_seed(&m2, &group_id, &hash(&m2_secret));
+
+    // Full and committed: the reveal phase has started and commitments are closed.
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Open);
+    let reveal_deadline = client.get_reveal_deadline(&group_id).unwrap();
+    assert_eq!(
+        client.try_commit_seed(&m2, &group_id, &hash(&m1_secret)).err(),
+        Some(Ok(Error::RevealPending))
+    );
+    assert_eq!(client.try_leave_group(&m2, &group_id).err(), Some(Ok(Error::RevealPending)));
+    assert_eq!(
+        client.try_reveal_seed(&m1, &group_id, &admin_secret).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.reveal_seed(&admin, &group_id, &admin_secret);
+    client.reveal_seed(&m2, &group_id, &m2_secret);
+    assert_eq!(client.try_finalize_seed(&m2, &group_id).err(), Some(Ok(Error::RevealPending)));
+
+    // m1 never reveals; their commitment stands in after the window.
+    env.ledger().with_mut(|li| li.timestamp = reveal_deadline);
+    client.finalize_seed(&m2, &group_id);
+    let group = client.get_group(&group_id);
+    assert_eq!(group.status, GroupStatus::Active);
+
+    let transcript = client.get_order_transcript(&group_id).unwrap();
+    assert_eq!(transcript.members, Vec::from_array(&env, [admin.clone(), m1.clone(), m2.clone()]));
+    assert_eq!(transcript.revealed, Vec::from_array(&env, [true, false, true]));
+    assert_eq!(
+        transcript.inputs,
+        Vec::from_array(&env, [admin_secret.clone(), hash(&m1_secret), m2_secret.clone()])
+    );
+
+    // Recompute the seed and the shuffle as an off-chain verifier would.
+    let mut preimage = group_id.to_bytes();
+    for input in transcript.inputs.iter() {
+        preimage.append(&soroban_sdk::Bytes::from(input));
+    }
+    let seed: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(transcript.seed, seed);
+
+    let mut order = [0u32, 1, 2];
+    for i in (1..3u32).rev() {
+        let mut data = soroban_sdk::Bytes::from(seed.clone());
+        data.append(&soroban_sdk::Bytes::from_array(&env, &i.to_be_bytes()));
+        let digest = env.crypto().sha256(&data).to_array();
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&digest[0..8]);
+        let j = (u64::from_be_bytes(word) % (i as u64 + 1)) as usize;
+        order.swap(i as usize, j);
+    }
+    assert_eq!(group.payout_order, Vec::from_array(&env, order));
+}
+
+#[test]
+fn test_commit_reveal_waits_for_every_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let hash = |bytes: &BytesN<32>| -> BytesN<32> {
+        env.crypto().sha256(&soroban_sdk::Bytes::from(bytes.clone())).into()
+    };
+
+    // Nobody commits: the full group stays Open rather than deriving its
+    // order from the public group id alone.
+    let no_commits = String::from_str(&env, "cr-none");
+    client.create_group(
+        &admin, &no_commits, &String::from_str(&env, "No Commits"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 10_000),
+        &true, &admin, &None,
+    );
+    client.set_payout_mode(&admin, &no_commits, &PayoutMode::CommitReveal(3_600));
+    client.join_group(&Address::generate(&env), &no_commits);
+    client.join_group(&Address::generate(&env), &no_commits);
+    assert_eq!(client.get_group(&no_commits).status, GroupStatus::Open);
+    assert_eq!(client.get_reveal_deadline(&no_commits), None);
+    env.ledger().with_mut(|li| li.timestamp += 3_601);
+    assert_eq!(
+        client.try_finalize_seed(&admin, &no_commits).err(),
+        Some(Ok(Error::RevealPending))
+    );
+
+    // Only the group admin commits: no reveal phase that the admin's secret
+    // alone would decide.
+    let owner = Address::generate(&env);
+    let admin_only = String::from_str(&env, "cr-admin");
+    client.create_group(
+        &owner, &admin_only, &String::from_str(&env, "Admin Only"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 10_000),
+        &true, &owner, &None,
+    );
+    client.set_payout_mode(&owner, &admin_only, &PayoutMode::CommitReveal(3_600));
+    let owner_secret = BytesN::<32>::random(&env);
+    client.commit_seed(&owner, &admin_only, &hash(&owner_secret));
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &admin_only);
+    client.join_group(&m2, &admin_only);
+    assert_eq!(client.get_reveal_deadline(&admin_only), None);
+    assert_eq!(
+        client.try_reveal_seed(&owner, &admin_only, &owner_secret).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+
+    // The group activates once the others have committed too.
+    let m1_secret = BytesN::<32>::random(&env);
+    let m2_secret = BytesN::<32>::random(&env);
+    client.commit_seed(&m1, &admin_only, &hash(&m1_secret));
+    assert_eq!(client.get_reveal_deadline(&admin_only), None);
+    client.commit_seed(&m2, &admin_only, &hash(&m2_secret));
+    assert!(client.get_reveal_deadline(&admin_only).is_some());
+    client.reveal_seed(&owner, &admin_only, &owner_secret);
+    client.reveal_seed(&m1, &admin_only, &m1_secret);
+    client.reveal_seed(&m2, &admin_only, &m2_secret);
+    assert_eq!(client.get_group(&admin_only).status, GroupStatus::Active);
+    assert_eq!(client.get_order_transcript(&admin_only).unwrap().members.len(), 3);
+}
+
+// ─── Payout swap tests ──────────────────────────────────────────────
+
+#[test]
+fn test_swap_trades_unpaid_positions_for_premium() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, m1, m2, outsider, client, group_id) = setup_full_group(&env);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+    let by_join_order = [admin.clone(), m1.clone(), m2.clone()];
+    let holder = |round: u32| {
+        let order = client.get_group(&group_id).payout_order;
+        by_join_order[order.get(round - 1).unwrap() as usize].clone()
+    };
+
+    // Pay out round 1.
+    let group = client.get_group(&group_id);
+    env.ledger().with_mut(|li| li.timestamp = group.start_timestamp + 1);
+    for member in by_join_order.iter() {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.get_group(&group_id).current_round, 2);
+
+    let (early, late) = (holder(2), holder(3));
+    assert_eq!(
+        client.try_propose_swap(&late, &group_id, &3, &1, &0).err(),
+        Some(Ok(Error::InvalidRound))
+    );
+    assert_eq!(
+        client.try_propose_swap(&early, &group_id, &3, &2, &0).err(),
+        Some(Ok(Error::InvalidSwap))
+    );
+
+    let premium = 5_000_000;
+    let (late_before, early_before) = (xlm.balance(&late), xlm.balance(&early));
+    client.propose_swap(&late, &group_id, &3, &2, &premium);
+    assert_eq!(xlm.balance(&late), late_before - premium);
+    assert_eq!(client.get_swap_offer(&group_id, &late).unwrap().their_round, 2);
+
+    assert_eq!(
+        client.try_accept_swap(&outsider, &group_id, &late).err(),
+        Some(Ok(Error::InvalidSwap))
+    );
+    client.accept_swap(&early, &group_id, &late);
+
+    assert_eq!(holder(2), late);
+    assert_eq!(holder(3), early);
+    assert_eq!(xlm.balance(&early), early_before + premium);
+    assert_eq!(client.get_swap_offer(&group_id, &late), None);
+    assert_eq!(
+        client.try_cancel_swap(&late, &group_id).err(),
+        Some(Ok(Error::InvalidSwap))
+    );
+}
+
+// ─── Collateral tests ───────────────────────────────────────────────
+
+#[test]
+fn test_collateral_slashed_on_default_and_released_on_completion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let group_id = String::from_str(&env, "collateral");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Collateral"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+    let native = client.get_native_token().unwrap();
+    let xlm = token::Client::new(&env, &native);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    for member in [&admin, &m1, &m2] {
+        token::StellarAssetClient::new(&env, &native).mint(member, &10_000_000_000);
+    }
+
+    assert_eq!(
+        client.try_set_collateral(&admin, &group_id, &50_000_000).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.set_collateral(&admin, &group_id, &300_000_000);
+    client.set_default_policy(&admin, &group_id, &DefaultPolicy::Collateral);
+    assert_eq!(xlm.balance(&admin), 10_000_000_000 - 300_000_000);
+
+    // m2 is paid first, then stops contributing.
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [2u32, 0, 1]));
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    assert_eq!(
+        client.try_set_collateral(&admin, &group_id, &0).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.approve_payout_order(&m1, &group_id);
+    assert_eq!(client.get_collateral(&group_id, &m2), 300_000_000);
+    assert_eq!(client.get_group_collateral(&group_id), 900_000_000);
+
+    let start = client.get_group(&group_id).start_timestamp;
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+
+    // Rounds 2 and 3 are covered out of m2's collateral. Round 2 closes as
+    // soon as m2 is marked defaulted, round 3 on the last contribution.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    assert_eq!(client.get_collateral(&group_id, &m2), 200_000_000);
+    assert_eq!(client.get_round_contributions(&group_id, &2).len(), 3);
+    assert_eq!(client.get_group(&group_id).current_round, 3);
+
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    assert_eq!(client.get_collateral(&group_id, &m2), 0);
+    assert_eq!(client.get_round_contributions(&group_id, &3).len(), 3);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.status, GroupStatus::Completed);
+    assert_eq!(client.get_member(&m2, &group_id).status, MemberStatus::Defaulted);
+    assert_eq!(client.get_group_collateral(&group_id), 0);
+    assert_eq!(xlm.balance(&m1), 10_000_000_000 - 300_000_000 + 294_000_000);
+    assert_eq!(
+        xlm.balance(&treasury),
+        client.get_fees_collected(&group_id) + 100_000_000
+    );
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_collateral_slashed_under_default_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let group_id = String::from_str(&env, "collateral-dflt");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Collateral"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+    let native = client.get_native_token().unwrap();
+    let xlm = token::Client::new(&env, &native);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    for member in [&admin, &m1, &m2] {
+        token::StellarAssetClient::new(&env, &native).mint(member, &10_000_000_000);
+    }
+
+    // No set_default_policy: the group keeps ShrinkPot.
+    client.set_collateral(&admin, &group_id, &100_000_000);
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [2u32, 0, 1]));
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    client.approve_payout_order(&m1, &group_id);
+    assert_eq!(client.get_group(&group_id).default_policy, DefaultPolicy::ShrinkPot);
+
+    let start = client.get_group(&group_id).start_timestamp;
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+
+    // m2 skips round 2: their collateral fills the pot instead of going to
+    // the treasury at the end.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    assert_eq!(client.get_collateral(&group_id, &m2), 0);
+    assert_eq!(client.get_round_contributions(&group_id, &2).len(), 3);
+    assert_eq!(client.get_round_payouts(&group_id, &2).get(0).unwrap().amount, 294_000_000);
+
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+    assert_eq!(xlm.balance(&treasury), client.get_fees_collected(&group_id));
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+// ─── Default policy tests ───────────────────────────────────────────
+
+#[test]
+fn test_top_up_policy_restores_pot_after_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "top-up");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Top Up"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    client.set_default_policy(&admin, &group_id, &DefaultPolicy::TopUp);
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [2u32, 0, 1]));
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    client.approve_payout_order(&m1, &group_id);
+    fund_members(&env, &client, &group_id);
+
+    let start = client.get_group(&group_id).start_timestamp;
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+
+    // m2 defaults on round 2, which was already paid for by the others: it
+    // closes with the smaller pot instead of stalling.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    assert_eq!(
+        client.try_retry_distribution(&admin, &group_id).err(),
+        Some(Ok(Error::NotAllPaid))
+    );
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    assert_eq!(client.get_round_payouts(&group_id, &2).get(0).unwrap().amount, 196_000_000);
+
+    // From round 3 the others cover m2's share between them.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let contributions = client.get_round_contributions(&group_id, &3);
+    assert_eq!(contributions.get(0).unwrap().amount, 150_000_000);
+    let payout = client.get_round_payouts(&group_id, &3).get(0).unwrap();
+    assert_eq!(payout.recipient, m1);
+    assert_eq!(payout.amount, 294_000_000);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+}
+
+#[test]
+fn test_reserve_covers_defaults_and_returns_leftover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let group_id = String::from_str(&env, "reserve");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Reserve"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &treasury, &None,
+    );
+    assert_eq!(
+        client.try_set_reserve_bps(&admin, &group_id, &10_001).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.set_reserve_bps(&admin, &group_id, &5_000);
+    client.set_default_policy(&admin, &group_id, &DefaultPolicy::Reserve);
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [2u32, 0, 1]));
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    client.approve_payout_order(&m1, &group_id);
+    fund_members(&env, &client, &group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    let start = client.get_group(&group_id).start_timestamp;
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.get_reserve(&group_id), 150_000_000);
+    assert_eq!(client.get_round_payouts(&group_id, &1).get(0).unwrap().amount, 147_000_000);
+
+    // m2's share of rounds 2 and 3 comes out of the reserve.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    assert_eq!(client.get_round_payouts(&group_id, &2).get(0).unwrap().amount, 147_000_000);
+    assert_eq!(client.get_reserve(&group_id), 200_000_000);
+    assert_eq!(client.get_reserve_claim(&group_id, &m1), 100_000_000);
+    assert_eq!(client.get_reserve_claim(&group_id, &m2), 0);
+
+    let admin_before = xlm.balance(&admin);
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    assert_eq!(client.get_round_payouts(&group_id, &3).get(0).unwrap().amount, 147_000_000);
+
+    // The 250 left is split between the two members in good standing.
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+    assert_eq!(client.get_reserve(&group_id), 0);
+    assert_eq!(xlm.balance(&admin), admin_before - 100_000_000 + 125_000_000);
+    assert_eq!(xlm.balance(&treasury), client.get_fees_collected(&group_id));
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_cure_repays_reserve_cover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "reserve-cure");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Reserve Cure"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    client.set_reserve_bps(&admin, &group_id, &5_000);
+    client.set_default_policy(&admin, &group_id, &DefaultPolicy::Reserve);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    let start = client.get_group(&group_id).start_timestamp;
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+
+    // The reserve covers m2's round 2.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    assert_eq!(client.get_reserve(&group_id), 200_000_000);
+    let recipient = client.get_round_payouts(&group_id, &2).get(0).unwrap().recipient;
+
+    // Curing pays the reserve back rather than paying round 2 a second time.
+    let before = xlm.balance(&recipient);
+    client.cure_default(&m2, &group_id);
+    assert_eq!(client.get_reserve(&group_id), 300_000_000);
+    assert_eq!(xlm.balance(&recipient), before);
+
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+    assert_eq!(client.get_reserve(&group_id), 0);
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+// ─── Late fee tests ─────────────────────────────────────────────────
+
+#[test]
+fn test_escalating_late_fee_paid_to_other_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "late-fee");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Late Fee"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100),
+        &true, &admin, &None,
+    );
+    let mut config = LateFeeConfig {
+        fee: LateFee::Flat(-1),
+        escalating: true,
+        destination: LateFeeDestination::Members,
+    };
+    assert_eq!(
+        client.try_set_late_fee(&admin, &group_id, &Some(config.clone())).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    config.fee = LateFee::Bps(100);
+    client.set_late_fee(&admin, &group_id, &Some(config.clone()));
+    assert_eq!(client.get_late_fee(&group_id), Some(config));
+
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
+    let xlm = token::Client::new(&env, &client.get_native_token().unwrap());
+
+    // Two and a half days late: three days of 1% each, split between the
+    // other two members.
+    let deadline = client.get_round_deadline(&group_id, &1);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 216_000);
+    let before = [xlm.balance(&admin), xlm.balance(&m1), xlm.balance(&m2)];
+    client.contribute(&m2, &group_id);
+    assert_eq!(xlm.balance(&m2), before[2] - 100_000_000 - 3_000_000);
+    assert_eq!(xlm.balance(&admin), before[0] + 1_500_000);
+    assert_eq!(xlm.balance(&m1), before[1] + 1_500_000);
+    assert_eq!(client.get_member(&m2, &group_id).total_contributed, 100_000_000);
+}
+
+// ─── Schedule tests ─────────────────────────────────────────────────
+
+#[test]
+fn test_calendar_monthly_deadlines_follow_month_ends() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    // 2024-01-31 00:00:00 UTC
+    let start = 1_706_659_200u64;
+    env.ledger().with_mut(|li| li.timestamp = start - 1_000);
+    let group_id = String::from_str(&env, "calendar");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Calendar"), &100_000_000, &3,
+        &Frequency::CalendarMonthly, &start,
+        &true, &admin, &None,
+    );
+
+    // 2024-02-29 (leap year), 2024-03-31.
+    assert_eq!(client.get_round_deadline(&group_id, &1), 1_709_164_800);
+    assert_eq!(client.get_round_deadline(&group_id, &2), 1_711_843_200);
+}
+
+#[test]
+fn test_custom_frequency_and_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let start = env.ledger().timestamp() + 100;
+    assert_eq!(
+        client.try_create_group(
+            &admin, &String::from_str(&env, "too-short"), &String::from_str(&env, "Short"),
+            &100_000_000, &3, &Frequency::Custom(60), &start, &true, &admin, &None,
+        ).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+
+    let group_id = String::from_str(&env, "custom");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Custom"), &100_000_000, &3,
+        &Frequency::Custom(7_200), &start,
+        &true, &admin, &None,
+    );
+    assert_eq!(client.get_group(&group_id).grace_period, 259_200);
+    client.set_payout_mode(
+        &admin,
+        &group_id,
+        &PayoutMode::Auction(AuctionConfig { sealed: false, bid_window: 1_800 }),
+    );
+    assert_eq!(
+        client.try_set_grace_period(&admin, &group_id, &600).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Shuffled);
+    client.set_grace_period(&admin, &group_id, &600);
+
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    fund_members(&env, &client, &group_id);
+
+    let deadline = client.get_round_deadline(&group_id, &1);
+    assert_eq!(deadline, start + 7_200);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 601);
+    assert_eq!(
+        client.try_contribute(&m1, &group_id).err(),
+        Some(Ok(Error::PaymentWindowClosed))
+    );
+    client.force_end_round(&admin, &group_id);
+    assert_eq!(client.get_member(&m1, &group_id).status, MemberStatus::Defaulted);
+}
+
+#[test]
+fn test_extension_vote_and_pause_shift_deadlines() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "extend");
+    let start = env.ledger().timestamp() + 100;
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Extend"), &100_000_000, &3,
+        &Frequency::Weekly, &start, &true, &admin, &None,
+    );
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+
+    let deadline = client.get_round_deadline(&group_id, &1);
+    assert_eq!(
+        client.try_vote_extension(&m1, &group_id).err(),
+        Some(Ok(Error::NoProposal))
+    );
+    assert_eq!(
+        client.try_propose_extension(&m1, &group_id, &0).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.propose_extension(&m1, &group_id, &86_400);
+    assert_eq!(
+        client.try_propose_extension(&m2, &group_id, &3_600).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(client.get_round_deadline(&group_id, &1), deadline);
+
+    client.vote_extension(&m2, &group_id);
+    assert_eq!(client.get_extension_proposal(&group_id), None);
+    assert_eq!(client.get_round_deadline(&group_id, &1), deadline + 86_400);
+    assert_eq!(client.get_round_deadline(&group_id, &2), start + 2 * 604_800 + 86_400);
+
+    client.pause_group(&admin, &group_id);
+    env.ledger().with_mut(|li| li.timestamp += 10_000);
+    client.resume_group(&admin, &group_id);
+    assert_eq!(client.get_round_deadline(&group_id, &1), deadline + 96_400);
+    assert_eq!(client.get_round_deadline(&group_id, &3), start + 3 * 604_800 + 96_400);
+}
+
+#[test]
+fn test_private_group_invites_and_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "private");
+    let start = env.ledger().timestamp() + 10_000;
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Private"), &100_000_000, &4,
+        &Frequency::Weekly, &start, &false, &admin, &None,
+    );
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m3 = Address::generate(&env);
+    assert_eq!(client.try_join_group(&m1, &group_id).err(), Some(Ok(Error::GroupIsPrivate)));
+
+    client.invite_member(&admin, &group_id, &m1, &None);
+    client.invite_member(&admin, &group_id, &m2, &Some(env.ledger().timestamp() + 100));
+    let code = Bytes::from_slice(&env, b"open sesame");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    client.add_invite_code(&admin, &group_id, &code_hash, &m3, &None);
+    let spare: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(&env, b"spare")).into();
+    client.add_invite_code(&admin, &group_id, &spare, &m2, &None);
+    assert_eq!(client.get_group(&group_id).open_invites, 4);
+
+    client.join_group(&m1, &group_id);
+    assert_eq!(client.get_invite(&group_id, &m1), None);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(client.try_join_group(&m2, &group_id).err(), Some(Ok(Error::GroupIsPrivate)));
+    client.revoke_invite(&admin, &group_id, &m2);
+    client.revoke_invite_code(&admin, &group_id, &spare);
+    assert_eq!(client.get_group(&group_id).open_invites, 1);
+
+    assert_eq!(
+        client.try_join_with_code(&m3, &group_id, &Bytes::from_slice(&env, b"guess")).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+    // Seeing m3's code is not enough to take their seat.
+    assert_eq!(
+        client.try_join_with_code(&m2, &group_id, &code).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+    client.join_with_code(&m3, &group_id, &code);
+    assert_eq!(
+        client.try_join_with_code(&m3, &group_id, &code).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+    assert_eq!(client.get_group(&group_id).open_invites, 0);
+    assert_eq!(client.get_members(&group_id).len(), 3);
+}
+
+#[test]
+fn test_invite_codes_keep_approval_and_waitlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "public-codes");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Public Codes"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 10_000), &true, &admin, &None,
+    );
+    // Without a proposed order the group stays Open once full.
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    let hash = |code: &Bytes| -> BytesN<32> { env.crypto().sha256(code).into() };
+    let first = Bytes::from_slice(&env, b"first");
+    let second = Bytes::from_slice(&env, b"second");
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m3 = Address::generate(&env);
+    client.add_invite_code(&admin, &group_id, &hash(&first), &m1, &None);
+    client.add_invite_code(&admin, &group_id, &hash(&second), &m3, &None);
+
+    // Under AdminApproval a code files a request instead of admitting.
+    client.set_join_policy(&admin, &group_id, &JoinPolicy::AdminApproval);
+    client.join_with_code(&m1, &group_id, &first);
+    assert!(client.get_join_request(&group_id, &m1).is_some());
+    assert_eq!(client.get_members(&group_id).len(), 1);
+    client.approve_join(&admin, &group_id, &m1);
+    assert_eq!(client.get_members(&group_id).len(), 2);
+
+    // Once the group is full, a code holder is queued on the waitlist.
+    client.set_join_policy(&admin, &group_id, &JoinPolicy::Open);
+    client.join_group(&m2, &group_id);
+    client.join_with_code(&m3, &group_id, &second);
+    assert_eq!(client.get_members(&group_id).len(), 3);
+    assert_eq!(client.get_waitlist(&group_id), Vec::from_array(&env, [m3.clone()]));
+}
+
+#[test]
+fn test_invite_codes_reject_expired_revoked_and_reused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "codes");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Codes"), &100_000_000, &4,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 10_000), &false, &admin, &None,
+    );
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m3 = Address::generate(&env);
+    let hash = |code: &Bytes| -> BytesN<32> { env.crypto().sha256(code).into() };
+
+    let expiring = Bytes::from_slice(&env, b"expiring");
+    let revoked = Bytes::from_slice(&env, b"revoked");
+    let single = Bytes::from_slice(&env, b"single");
+    assert_eq!(
+        client
+            .try_add_invite_code(&admin, &group_id, &hash(&expiring), &m2, &Some(env.ledger().timestamp()))
+            .err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_add_invite_code(&m1, &group_id, &hash(&single), &m1, &None).err(),
+        Some(Ok(Error::AdminOnly))
+    );
+    client.add_invite_code(&admin, &group_id, &hash(&expiring), &m2, &Some(env.ledger().timestamp() + 100));
+    client.add_invite_code(&admin, &group_id, &hash(&revoked), &m1, &None);
+    client.add_invite_code(&admin, &group_id, &hash(&single), &m1, &None);
+
+    client.revoke_invite_code(&admin, &group_id, &hash(&revoked));
+    assert_eq!(
+        client.try_revoke_invite_code(&admin, &group_id, &hash(&revoked)).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+    assert_eq!(
+        client.try_join_with_code(&m1, &group_id, &revoked).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+
+    // Each code admits one member.
+    client.join_with_code(&m1, &group_id, &single);
+    assert_eq!(
+        client.try_join_with_code(&m2, &group_id, &single).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(
+        client.try_join_with_code(&m2, &group_id, &expiring).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+
+    client.invite_member(&admin, &group_id, &m3, &None);
+    client.revoke_invite(&admin, &group_id, &m3);
+    assert_eq!(client.try_join_group(&m3, &group_id).err(), Some(Ok(Error::GroupIsPrivate)));
+    assert_eq!(
+        client.try_revoke_invite(&admin, &group_id, &m3).err(),
+        Some(Ok(Error::InviteNotFound))
+    );
+    assert_eq!(client.get_members(&group_id).len(), 2);
+}
+
+#[test]
+fn test_join_requests_need_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "vetted");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Vetted"), &100_000_000, &4,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100), &true, &admin, &None,
+    );
+    let native = client.get_native_token().unwrap();
+    let xlm = token::Client::new(&env, &native);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m3 = Address::generate(&env);
+    for member in [&admin, &m1, &m2, &m3] {
+        token::StellarAssetClient::new(&env, &native).mint(member, &10_000_000_000);
+    }
+    client.set_collateral(&admin, &group_id, &100_000_000);
+    assert_eq!(
+        client.try_set_join_policy(&admin, &group_id, &JoinPolicy::MemberQuorum(4)).err(),
+        Some(Ok(Error::InvalidConfig))
+    );
+    client.set_join_policy(&admin, &group_id, &JoinPolicy::MemberQuorum(2));
+
+    client.join_group(&m1, &group_id);
+    assert_eq!(client.get_members(&group_id).len(), 1);
+    assert_eq!(xlm.balance(&m1), 10_000_000_000 - 100_000_000);
+    client.approve_join(&admin, &group_id, &m1);
+    assert_eq!(client.get_member(&m1, &group_id).join_order, 1);
+    assert_eq!(client.get_collateral(&group_id, &m1), 100_000_000);
+
+    client.join_group(&m2, &group_id);
+    client.join_group(&m3, &group_id);
+    assert_eq!(client.get_join_requests(&group_id), Vec::from_array(&env, [m2.clone(), m3.clone()]));
+    assert_eq!(
+        client.try_approve_join(&m3, &group_id, &m2).err(),
+        Some(Ok(Error::AdminOnly))
+    );
+    client.approve_join(&m1, &group_id, &m2);
+    assert_eq!(client.get_join_request(&group_id, &m2).unwrap().approvals.len(), 1);
+    client.approve_join(&admin, &group_id, &m2);
+    assert_eq!(client.get_members(&group_id).len(), 3);
+
+    client.reject_join(&admin, &group_id, &m3);
+    assert_eq!(xlm.balance(&m3), 10_000_000_000);
+    assert_eq!(
+        client.try_withdraw_join_request(&m3, &group_id).err(),
+        Some(Ok(Error::NoJoinRequest))
+    );
+    assert_eq!(client.get_join_requests(&group_id).len(), 0);
+}
+
+#[test]
+fn test_join_requests_reject_unapproved_and_repeat_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "gated");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Gated"), &100_000_000, &4,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100), &true, &admin, &None,
+    );
+    client.set_join_policy(&admin, &group_id, &JoinPolicy::AdminApproval);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+
+    assert_eq!(
+        client.try_approve_join(&admin, &group_id, &m1).err(),
+        Some(Ok(Error::NoJoinRequest))
+    );
+    assert_eq!(
+        client.try_reject_join(&admin, &group_id, &m1).err(),
+        Some(Ok(Error::NoJoinRequest))
+    );
+
+    // Requesting does not admit the applicant.
+    client.join_group(&m1, &group_id);
+    assert_eq!(client.try_leave_group(&m1, &group_id).err(), Some(Ok(Error::NotMember)));
+    assert_eq!(
+        client.try_approve_join(&m2, &group_id, &m1).err(),
+        Some(Ok(Error::AdminOnly))
+    );
+
+    client.approve_join(&admin, &group_id, &m1);
+    assert_eq!(
+        client.try_approve_join(&admin, &group_id, &m1).err(),
+        Some(Ok(Error::NoJoinRequest))
+    );
+
+    // Requests still pending at the start can no longer be approved.
+    client.join_group(&m2, &group_id);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(
+        client.try_approve_join(&admin, &group_id, &m2).err(),
+        Some(Ok(Error::StartDateAlreadyPassed))
+    );
+    assert_eq!(client.get_members(&group_id), Vec::from_array(&env, [admin.clone(), m1.clone()]));
+}
+
+#[test]
+fn test_leave_group_compacts_membership() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "leavers");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Leavers"), &100_000_000, &5,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100), &true, &admin, &None,
+    );
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m3 = Address::generate(&env);
+    for member in [&m1, &m2, &m3] {
+        client.join_group(member, &group_id);
+    }
+    assert_eq!(client.try_leave_group(&admin, &group_id).err(), Some(Ok(Error::AdminCannotLeave)));
+
+    client.leave_group(&m1, &group_id);
+    assert_eq!(client.get_user_groups(&m1).len(), 0);
+    assert_eq!(client.get_member(&m2, &group_id).join_order, 1);
+    assert_eq!(client.get_member(&m3, &group_id).join_order, 2);
+    assert_eq!(client.try_leave_group(&m1, &group_id).err(), Some(Ok(Error::NotMember)));
+
+    client.remove_member(&admin, &group_id, &m2);
+    assert_eq!(client.get_user_groups(&m2).len(), 0);
+    assert_eq!(client.get_member(&m3, &group_id).join_order, 1);
+    assert_eq!(client.get_members(&group_id), Vec::from_array(&env, [admin.clone(), m3.clone()]));
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(
+        client.try_leave_group(&m3, &group_id).err(),
+        Some(Ok(Error::StartDateAlreadyPassed))
+    );
+}
+
+#[test]
+fn test_leave_group_rejected_once_started() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, m1, _, outsider, client, group_id) = setup_full_group(&env);
+    assert_eq!(
+        client.try_leave_group(&m1, &group_id).err(),
+        Some(Ok(Error::GroupNotAcceptingMembers))
+    );
+
+    let admin = Address::generate(&env);
+    let open_group = String::from_str(&env, "open");
+    let start = env.ledger().timestamp() + 100;
+    client.create_group(
+        &admin, &open_group, &String::from_str(&env, "Open"), &100_000_000, &4,
+        &Frequency::Weekly, &start, &true, &admin, &None,
+    );
+    let member = Address::generate(&env);
+    client.join_group(&member, &open_group);
+    assert_eq!(client.try_leave_group(&outsider, &open_group).err(), Some(Ok(Error::NotMember)));
+
+    // Past the start timestamp the group is still Open but the seat is locked in.
+    env.ledger().with_mut(|li| li.timestamp = start);
+    assert_eq!(
+        client.try_leave_group(&member, &open_group).err(),
+        Some(Ok(Error::StartDateAlreadyPassed))
+    );
+    assert_eq!(client.get_members(&open_group).len(), 2);
+}
+
+#[test]
+fn test_transfer_seat_mid_cycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, m1, m2, heir, client, group_id) = setup_full_group(&env);
+    let native = client.get_native_token().unwrap();
+    token::StellarAssetClient::new(&env, &native).mint(&heir, &10_000_000_000);
+
+    let group = client.get_group(&group_id);
+    env.ledger().with_mut(|li| li.timestamp = group.start_timestamp + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+    let before = client.get_member(&m1, &group_id);
+
+    assert_eq!(
+        client.try_transfer_seat(&group_id, &admin, &heir).err(),
+        Some(Ok(Error::AdminCannotLeave))
+    );
+    assert_eq!(
+        client.try_transfer_seat(&group_id, &m1, &m2).err(),
+        Some(Ok(Error::AlreadyMember))
+    );
+    client.transfer_seat(&group_id, &m1, &heir);
+
+    let after = client.get_member(&heir, &group_id);
+    assert_eq!(after.join_order, before.join_order);
+    assert_eq!(after.total_contributed, before.total_contributed);
+    assert_eq!(after.has_received_payout, before.has_received_payout);
+    assert_eq!(
+        client.get_members(&group_id),
+        Vec::from_array(&env, [admin.clone(), heir.clone(), m2.clone()])
+    );
+    assert_eq!(client.get_user_groups(&m1).len(), 0);
+    assert_eq!(client.get_user_groups(&heir), Vec::from_array(&env, [group_id.clone()]));
+    assert_eq!(client.try_contribute(&m1, &group_id).err(), Some(Ok(Error::NotMember)));
+
+    for member in [&admin, &heir, &m2] {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.get_group(&group_id).current_round, 3);
+}
+
+#[test]
+fn test_transfer_seat_rejects_non_members_and_taken_addresses() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, m1, _, outsider, client, group_id) = setup_full_group(&env);
+    let heir = Address::generate(&env);
+    let waiting = Address::generate(&env);
+    client.join_group(&waiting, &group_id);
+    assert_eq!(client.get_waitlist_position(&group_id, &waiting), Some(1));
+
+    assert_eq!(
+        client.try_transfer_seat(&group_id, &outsider, &heir).err(),
+        Some(Ok(Error::NotMember))
+    );
+    assert_eq!(
+        client.try_transfer_seat(&group_id, &m1, &admin).err(),
+        Some(Ok(Error::AlreadyMember))
+    );
+    assert_eq!(
+        client.try_transfer_seat(&group_id, &m1, &waiting).err(),
+        Some(Ok(Error::AlreadyMember))
+    );
+    assert_eq!(client.get_member(&m1, &group_id).join_order, 1);
+}
+
+#[test]
+fn test_waitlist_promotion_and_defaulted_seat() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "waitlist");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Waitlist"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100), &true, &admin, &None,
+    );
+    // A fixed payout order keeps the full group Open until it is approved.
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [0u32, 1, 2]));
+    let [m1, m2, w1, w2, w3] = core::array::from_fn(|_| Address::generate(&env));
+    for member in [&m1, &m2, &w1, &w2] {
+        client.join_group(member, &group_id);
+    }
+    assert_eq!(client.get_waitlist_position(&group_id, &w2), Some(2));
+
+    client.leave_group(&m1, &group_id);
+    assert_eq!(client.get_member(&w1, &group_id).join_order, 2);
+    assert_eq!(client.get_waitlist(&group_id), Vec::from_array(&env, [w2.clone()]));
+
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [0u32, 1, 2]));
+    client.approve_payout_order(&m2, &group_id);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Active);
+    client.join_group(&w3, &group_id);
+    assert_eq!(client.get_waitlist_position(&group_id, &w3), Some(2));
+    client.leave_waitlist(&w3, &group_id);
+    assert_eq!(client.get_waitlist_position(&group_id, &w3), None);
+
+    let native = client.get_native_token().unwrap();
+    token::StellarAssetClient::new(&env, &native).mint(&w2, &10_000_000_000);
+    let deadline = client.get_round_deadline(&group_id, &1);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_201);
+    assert_eq!(
+        client.try_fill_defaulted_seat(&admin, &group_id, &m2).err(),
+        Some(Ok(Error::NotDefaulted))
+    );
+    client.mark_defaulted(&admin, &m2, &group_id);
+    client.fill_defaulted_seat(&admin, &group_id, &m2);
+
+    let seat = client.get_member(&w2, &group_id);
+    assert_eq!(seat.join_order, 1);
+    assert_eq!(seat.status, MemberStatus::PaidCurrentRound);
+    assert_eq!(seat.total_contributed, 100_000_000);
+    assert!(client.try_get_member(&m2, &group_id).is_err());
+    assert_eq!(client.get_waitlist(&group_id).len(), 0);
+    assert_eq!(
+        client.try_fill_defaulted_seat(&admin, &group_id, &w1).err(),
+        Some(Ok(Error::NotDefaulted))
+    );
+}
+
+#[test]
+fn test_filled_seat_starts_fresh_and_keeps_forfeit_in_group() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let treasury = Address::generate(&env);
+    let group_id = String::from_str(&env, "refill");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Refill"), &100_000_000, &3,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100), &true, &treasury, &None,
+    );
+    let native = client.get_native_token().unwrap();
+    let xlm = token::Client::new(&env, &native);
+    let [m1, m2, newcomer] = core::array::from_fn(|_| Address::generate(&env));
+    for member in [&admin, &m1, &m2, &newcomer] {
+        token::StellarAssetClient::new(&env, &native).mint(member, &10_000_000_000);
+    }
+    client.set_collateral(&admin, &group_id, &150_000_000);
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [0u32, 1, 2]));
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    client.approve_payout_order(&m1, &group_id);
+    client.join_group(&newcomer, &group_id);
+
+    let start = client.get_group(&group_id).start_timestamp;
+    env.ledger().with_mut(|li| li.timestamp = start + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+
+    // m2 misses round 2, covered out of their collateral, and is replaced.
+    client.contribute(&admin, &group_id);
+    client.contribute(&m1, &group_id);
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_200 + 1);
+    client.mark_defaulted(&admin, &m2, &group_id);
+    assert_eq!(client.get_member(&m2, &group_id).total_contributed, 200_000_000);
+    client.fill_defaulted_seat(&admin, &group_id, &m2);
+
+    let seat = client.get_member(&newcomer, &group_id);
+    assert_eq!(seat.total_contributed, 0);
+    assert_eq!(seat.status, MemberStatus::Active);
+    assert_eq!(client.get_collateral(&group_id, &newcomer), 150_000_000);
+    assert_eq!(client.get_reserve(&group_id), 50_000_000);
+
+    // The forfeited collateral is shared by the members, not the treasury.
+    for member in [&admin, &m1, &newcomer] {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+    assert_eq!(client.get_member(&newcomer, &group_id).total_contributed, 100_000_000);
+    assert_eq!(xlm.balance(&treasury), client.get_fees_collected(&group_id));
+    assert_eq!(xlm.balance(&client.address), 0);
+}
+
+#[test]
+fn test_fill_defaulted_seat_rejects_paid_seat() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, m1, m2, waiting, client, group_id) = setup_full_group(&env);
+    client.join_group(&waiting, &group_id);
+
+    let group = client.get_group(&group_id);
+    env.ledger().with_mut(|li| li.timestamp = group.start_timestamp + 1);
+    for member in [&admin, &m1, &m2] {
+        client.contribute(member, &group_id);
+    }
+    let paid = [admin.clone(), m1.clone(), m2.clone()]
+        .into_iter()
+        .find(|m| client.get_member(m, &group_id).has_received_payout)
+        .unwrap();
+
+    let deadline = client.get_round_deadline(&group_id, &2);
+    env.ledger().with_mut(|li| li.timestamp = deadline + 259_201);
+    client.mark_defaulted(&admin, &paid, &group_id);
+    assert_eq!(
+        client.try_fill_defaulted_seat(&admin, &group_id, &paid).err(),
+        Some(Ok(Error::SeatAlreadyPaid))
+    );
+    assert_eq!(client.get_waitlist_position(&group_id, &waiting), Some(1));
+}
+
+#[test]
+fn test_leaving_withdraws_proposed_payout_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_group(&env);
+    let group_id = String::from_str(&env, "fixed-leave");
+    client.create_group(
+        &admin, &group_id, &String::from_str(&env, "Fixed"), &100_000_000, &4,
+        &Frequency::Weekly, &(env.ledger().timestamp() + 100), &true, &admin, &None,
+    );
+    client.set_payout_mode(&admin, &group_id, &PayoutMode::Fixed);
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [3u32, 0, 1, 2]));
+    let [m1, m2, m3, m4] = core::array::from_fn(|_| Address::generate(&env));
+    client.join_group(&m1, &group_id);
+    client.join_group(&m2, &group_id);
+    client.approve_payout_order(&m1, &group_id);
+    client.approve_payout_order(&m2, &group_id);
+
+    client.leave_group(&m1, &group_id);
+    assert_eq!(client.get_proposed_order(&group_id), None);
+    assert_eq!(client.get_order_approvals(&group_id).len(), 0);
+
+    // The group fills without an order anyone approved, so it stays Open.
+    client.join_group(&m3, &group_id);
+    client.join_group(&m4, &group_id);
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Open);
+
+    client.propose_payout_order(&admin, &group_id, &Vec::from_array(&env, [3u32, 0, 1, 2]));
+    client.approve_payout_order(&m2, &group_id);
+    client.approve_payout_order(&m3, &group_id);
+    let group = client.get_group(&group_id);
+    assert_eq!(group.status, GroupStatus::Active);
+    assert_eq!(group.payout_order, Vec::from_array(&env, [3u32, 0, 1, 2]));
+}
@@ -54,6 +54,7 @@ const RAW_GROUP = {
   is_public: true,
   created_at: BigInt(1717200000),
   total_members: 5,
+  closed: false,
 };
 
 const EXPECTED_GROUP: GroupInfo = {
@@ -64,6 +65,7 @@ const EXPECTED_GROUP: GroupInfo = {
   isPublic: true,
   createdAt: 1717200000,
   totalMembers: 5,
+  closed: false,
 };
 
 describe("getAllGroupsInfo", () => {
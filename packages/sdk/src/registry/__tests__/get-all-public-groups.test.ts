@@ -51,6 +51,7 @@ const PUBLIC_GROUP = {
   is_public: true,
   created_at: BigInt(1717200000),
   total_members: 5,
+  closed: false,
 };
 
 const PRIVATE_GROUP = {
@@ -61,6 +62,7 @@ const PRIVATE_GROUP = {
   is_public: false,
   created_at: BigInt(1717200100),
   total_members: 3,
+  closed: false,
 };
 
 describe("getAllPublicGroups", () => {
@@ -127,6 +129,7 @@ describe("getAllPublicGroups", () => {
       isPublic: true,
       createdAt: 1717200000,
       totalMembers: 5,
+      closed: false,
     };
     expect(group).toEqual(expected);
   });
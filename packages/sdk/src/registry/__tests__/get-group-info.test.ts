@@ -53,6 +53,7 @@ const RAW_GROUP_INFO = {
   is_public: true,
   created_at: BigInt(1717200000),
   total_members: 6,
+  closed: false,
 };
 
 const EXPECTED: GroupInfo = {
@@ -63,6 +64,7 @@ const EXPECTED: GroupInfo = {
   isPublic: true,
   createdAt: 1717200000,
   totalMembers: 6,
+  closed: false,
 };
 
 describe("getGroupInfo", () => {
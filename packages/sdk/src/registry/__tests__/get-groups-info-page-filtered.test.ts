@@ -0,0 +1,129 @@
+import {
+  getGroupsInfoPageFiltered,
+  SdkConfig,
+} from "../get-groups-info-page-filtered";
+
+jest.mock("@stellar/stellar-sdk", () => {
+  const TransactionBuilderMock: any = jest.fn().mockImplementation(() => ({
+    addOperation: jest.fn().mockReturnThis(),
+    setTimeout: jest.fn().mockReturnThis(),
+    build: jest.fn().mockReturnValue({ toXDR: jest.fn().mockReturnValue("mock-xdr") }),
+  }));
+  TransactionBuilderMock.fromXDR = jest.fn();
+
+  return {
+    Contract: jest.fn().mockImplementation(() => ({
+      call: jest.fn().mockReturnValue("mock-op"),
+    })),
+    Account: jest.fn().mockImplementation(() => ({})),
+    BASE_FEE: "100",
+    TransactionBuilder: TransactionBuilderMock,
+    rpc: {
+      Server: jest.fn(),
+      Api: {
+        isSimulationError: jest.fn(),
+        GetTransactionStatus: { NOT_FOUND: "NOT_FOUND", SUCCESS: "SUCCESS" },
+      },
+    },
+    xdr: {
+      ScVal: { scvVoid: jest.fn().mockReturnValue("mock-void") },
+    },
+    scValToNative: jest.fn(),
+    nativeToScVal: jest.fn().mockReturnValue("mock-scval"),
+  };
+});
+
+const config: SdkConfig = {
+  contractId: "CREGISTRYCONTRACTID000000000000000000000000000000000000",
+  rpcUrl: "https://soroban-testnet.stellar.org",
+  networkPassphrase: "Test SDF Network ; September 2015",
+};
+
+function getSdk() {
+  return jest.requireMock("@stellar/stellar-sdk") as any;
+}
+
+function makeMockServer(overrides: Record<string, jest.Mock> = {}) {
+  const mockServer = {
+    simulateTransaction: jest.fn(),
+    ...overrides,
+  };
+  getSdk().rpc.Server.mockImplementation(() => mockServer);
+  return mockServer;
+}
+
+const RAW_GROUP = {
+  contract_address: "CSAVINGSADDR000000000000000000000000000000000000000000",
+  group_id: "group-001",
+  name: "Test Savings Group",
+  admin: "GADMIN0000000000000000000000000000000000000000000000000",
+  is_public: false,
+  created_at: BigInt(1717200000),
+  total_members: 5,
+  closed: false,
+};
+
+describe("getGroupsInfoPageFiltered", () => {
+  beforeEach(() => {
+    jest.clearAllMocks();
+    getSdk().rpc.Api.isSimulationError.mockReturnValue(false);
+  });
+
+  it("returns the mapped page of GroupInfo", async () => {
+    const mockServer = makeMockServer();
+    mockServer.simulateTransaction.mockResolvedValueOnce({
+      result: { retval: "mock-retval" },
+    });
+    getSdk().scValToNative.mockReturnValueOnce([RAW_GROUP]);
+
+    const result = await getGroupsInfoPageFiltered(config, { page: 0, pageSize: 10 });
+
+    expect(result).toHaveLength(1);
+    expect(result[0].groupId).toBe("group-001");
+    expect(result[0].isPublic).toBe(false);
+    expect(result[0].createdAt).toBe(1717200000);
+  });
+
+  it("invokes get_groups_info_page_filtered with void for unset filters", async () => {
+    const { Contract } = getSdk();
+    const mockContractInstance = { call: jest.fn().mockReturnValue("mock-op-call") };
+    Contract.mockImplementationOnce(() => mockContractInstance);
+
+    const mockServer = makeMockServer();
+    mockServer.simulateTransaction.mockResolvedValueOnce({
+      result: { retval: "mock-retval" },
+    });
+    getSdk().scValToNative.mockReturnValueOnce([]);
+
+    await getGroupsInfoPageFiltered(config, { page: 1, pageSize: 20, minMembers: 3 });
+
+    expect(mockContractInstance.call).toHaveBeenCalledWith(
+      "get_groups_info_page_filtered",
+      "mock-scval",
+      "mock-scval",
+      "mock-void",
+      "mock-scval",
+      "mock-void",
+    );
+    expect(getSdk().nativeToScVal).toHaveBeenCalledWith(3, { type: "u32" });
+  });
+
+  it("throws when the simulation returns an error", async () => {
+    const mockServer = makeMockServer();
+    mockServer.simulateTransaction.mockResolvedValueOnce({ error: "HostError: OutOfGas" });
+    getSdk().rpc.Api.isSimulationError.mockReturnValueOnce(true);
+
+    await expect(
+      getGroupsInfoPageFiltered(config, { page: 0, pageSize: 10 }),
+    ).rejects.toThrow("Registry contract simulation error: HostError: OutOfGas");
+  });
+
+  it("throws when simulation result is empty", async () => {
+    const mockServer = makeMockServer();
+    mockServer.simulateTransaction.mockResolvedValueOnce({ result: null });
+
+    await expect(
+      getGroupsInfoPageFiltered(config, { page: 0, pageSize: 10 }),
+    ).rejects.toThrow("Simulation returned empty result");
+  });
+});
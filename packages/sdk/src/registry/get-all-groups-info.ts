@@ -33,6 +33,8 @@ export interface GroupInfo {
   /** Unix timestamp (seconds) when the group was registered. */
   createdAt: number;
   totalMembers: number;
+  /** True once the group was cancelled or completed. */
+  closed: boolean;
 }
 
 /**
@@ -88,6 +90,7 @@ export async function getAllGroupsInfo(
     is_public: boolean;
     created_at: bigint | number;
     total_members: number;
+    closed: boolean;
   }>;
 
   return raw.map((g) => ({
@@ -98,5 +101,6 @@ export async function getAllGroupsInfo(
     isPublic: Boolean(g.is_public),
     createdAt: Number(g.created_at),
     totalMembers: Number(g.total_members),
+    closed: Boolean(g.closed),
   }));
 }
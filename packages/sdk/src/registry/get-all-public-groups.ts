@@ -31,11 +31,14 @@ export interface GroupInfo {
   /** Unix timestamp (seconds) when the group was registered. */
   createdAt: number;
   totalMembers: number;
+  /** True once the group was cancelled or completed. */
+  closed: boolean;
 }
 
 /**
  * Returns full `GroupInfo` for all **public** groups registered in the Registry contract.
- * Private groups (`is_public: false`) are excluded by the contract itself.
+ * Private groups (`is_public: false`) and closed (cancelled or completed)
+ * groups are excluded by the contract itself.
  *
  * This is a read-only simulation — no transaction is submitted and no auth is required.
  *
@@ -87,6 +90,7 @@ export async function getAllPublicGroups(
     is_public: boolean;
     created_at: bigint | number;
     total_members: number;
+    closed: boolean;
   }>;
 
   return raw.map((g) => ({
@@ -97,5 +101,6 @@ export async function getAllPublicGroups(
     isPublic: Boolean(g.is_public),
     createdAt: Number(g.created_at),
     totalMembers: Number(g.total_members),
+    closed: Boolean(g.closed),
   }));
 }
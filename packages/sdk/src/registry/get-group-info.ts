@@ -32,6 +32,8 @@ export interface GroupInfo {
   /** Unix timestamp (seconds) when the group was registered. */
   createdAt: number;
   totalMembers: number;
+  /** True once the group was cancelled or completed. */
+  closed: boolean;
 }
 
 /**
@@ -98,6 +100,7 @@ export async function getGroupInfo(
     is_public: boolean;
     created_at: bigint | number;
     total_members: number;
+    closed: boolean;
   };
 
   return {
@@ -108,5 +111,6 @@ export async function getGroupInfo(
     isPublic: Boolean(g.is_public),
     createdAt: Number(g.created_at),
     totalMembers: Number(g.total_members),
+    closed: Boolean(g.closed),
   };
 }
@@ -0,0 +1,141 @@
+import {
+  Contract,
+  nativeToScVal,
+  scValToNative,
+  TransactionBuilder,
+  BASE_FEE,
+  rpc,
+  Account,
+  xdr,
+} from "@stellar/stellar-sdk";
+
+// Fallback account used for read-only simulations when no publicKey is provided.
+const DUMMY_ACCOUNT =
+  "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+export interface SdkConfig {
+  /** Registry contract ID (Stellar address). */
+  contractId: string;
+  /** Soroban RPC endpoint URL. */
+  rpcUrl: string;
+  /** Network passphrase, e.g. "Test SDF Network ; September 2015". */
+  networkPassphrase: string;
+  /** Optional Stellar address used as the simulation source. Defaults to a dummy account. */
+  sourceAccount?: string;
+}
+
+/** Mirrors the `GroupInfo` struct from the Registry contract. */
+export interface GroupInfo {
+  contractAddress: string;
+  groupId: string;
+  name: string;
+  admin: string;
+  isPublic: boolean;
+  /** Unix timestamp (seconds) when the group was registered. */
+  createdAt: number;
+  totalMembers: number;
+  /** True once the group was cancelled or completed. */
+  closed: boolean;
+}
+
+export interface GroupsPageFilter {
+  /** Zero-based page index. */
+  page: number;
+  /** Groups per page. */
+  pageSize: number;
+  /** Only groups administered by this address. */
+  admin?: string;
+  /** Only groups with at least this many members. */
+  minMembers?: number;
+  /** Only groups with at most this many members. */
+  maxMembers?: number;
+}
+
+/**
+ * Returns one page of `GroupInfo` for all registered groups (public and
+ * private), optionally filtered by admin and member count.
+ *
+ * Calls the contract's `get_groups_info_page_filtered`. Earlier registry
+ * sources named it `get_all_groups_info_page_filtered`, which is over
+ * Soroban's 32-character function name limit, so that name never built.
+ *
+ * This is a read-only simulation — no transaction is submitted and no auth is required.
+ *
+ * @throws {Error} If the simulation fails or returns an empty result.
+ *
+ * @example
+ * ```ts
+ * const page = await getGroupsInfoPageFiltered(
+ *   {
+ *     contractId: "CREGISTRY...",
+ *     rpcUrl: "https://soroban-testnet.stellar.org",
+ *     networkPassphrase: "Test SDF Network ; September 2015",
+ *   },
+ *   { page: 0, pageSize: 20, minMembers: 3 },
+ * );
+ * ```
+ */
+export async function getGroupsInfoPageFiltered(
+  config: SdkConfig,
+  filter: GroupsPageFilter,
+): Promise<GroupInfo[]> {
+  const { contractId, rpcUrl, networkPassphrase } = config;
+  const source = config.sourceAccount ?? DUMMY_ACCOUNT;
+
+  const server = new rpc.Server(rpcUrl, { allowHttp: true });
+  const contract = new Contract(contractId);
+  const account = new Account(source, "0");
+
+  // `Option::None` is encoded as ScVal void.
+  const optional = (value: unknown, type: string) =>
+    value === undefined ? xdr.ScVal.scvVoid() : nativeToScVal(value, { type });
+
+  const tx = new TransactionBuilder(account, { fee: BASE_FEE, networkPassphrase })
+    .addOperation(
+      contract.call(
+        "get_groups_info_page_filtered",
+        nativeToScVal(filter.page, { type: "u32" }),
+        nativeToScVal(filter.pageSize, { type: "u32" }),
+        optional(filter.admin, "address"),
+        optional(filter.minMembers, "u32"),
+        optional(filter.maxMembers, "u32"),
+      ),
+    )
+    .setTimeout(30)
+    .build();
+
+  const result = await server.simulateTransaction(tx as any);
+
+  if (rpc.Api.isSimulationError(result)) {
+    throw new Error(
+      `Registry contract simulation error: ${(result as any).error}`,
+    );
+  }
+
+  const simResult = result as any;
+  if (!simResult.result?.retval) {
+    throw new Error("Simulation returned empty result");
+  }
+
+  const raw = scValToNative(simResult.result.retval) as Array<{
+    contract_address: string;
+    group_id: string;
+    name: string;
+    admin: string;
+    is_public: boolean;
+    created_at: bigint | number;
+    total_members: number;
+    closed: boolean;
+  }>;
+
+  return raw.map((g) => ({
+    contractAddress: String(g.contract_address),
+    groupId: String(g.group_id),
+    name: String(g.name),
+    admin: String(g.admin),
+    isPublic: Boolean(g.is_public),
+    createdAt: Number(g.created_at),
+    totalMembers: Number(g.total_members),
+    closed: Boolean(g.closed),
+  }));
+}
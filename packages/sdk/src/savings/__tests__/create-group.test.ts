@@ -28,6 +28,7 @@ jest.mock("@stellar/stellar-sdk", () => {
       ScVal: {
         scvVec: jest.fn().mockReturnValue("mock-scvec"),
         scvSymbol: jest.fn().mockReturnValue("mock-symbol"),
+        scvVoid: jest.fn().mockReturnValue("mock-void"),
       },
     },
   };
@@ -70,6 +71,7 @@ const validParams: CreateGroupParams = {
   frequency: "Monthly",
   startTimestamp: BigInt(Math.floor(Date.now() / 1000) + 86400),
   isPublic: true,
+  treasury: "GTREASURY00000000000000000000000000000000000000000000000",
 };
 
 describe("createGroup", () => {
@@ -119,6 +121,8 @@ describe("createGroup", () => {
       expect.anything(), // frequency (scvVec)
       expect.anything(), // startTimestamp
       expect.anything(), // isPublic
+      expect.anything(), // treasury
+      "mock-void", // options: None
     );
     expect(nativeToScVal).toHaveBeenCalledWith(validParams.admin, { type: "address" });
     expect(nativeToScVal).toHaveBeenCalledWith(validParams.groupId, { type: "string" });
@@ -126,6 +130,7 @@ describe("createGroup", () => {
     expect(nativeToScVal).toHaveBeenCalledWith(validParams.totalMembers, { type: "u32" });
     expect(nativeToScVal).toHaveBeenCalledWith(validParams.startTimestamp, { type: "u64" });
     expect(nativeToScVal).toHaveBeenCalledWith(validParams.isPublic, { type: "bool" });
+    expect(nativeToScVal).toHaveBeenCalledWith(validParams.treasury, { type: "address" });
     expect(xdr.ScVal.scvSymbol).toHaveBeenCalledWith(validParams.frequency);
   });
 
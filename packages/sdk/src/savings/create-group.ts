@@ -7,7 +7,14 @@ import {
   xdr,
 } from "@stellar/stellar-sdk";
 
-export type Frequency = "Weekly" | "BiWeekly" | "Monthly";
+/** Round cadence. `Custom` is a fixed round length in seconds (1 hour to 365 days). */
+export type Frequency =
+  | "Daily"
+  | "Weekly"
+  | "BiWeekly"
+  | "Monthly"
+  | "CalendarMonthly"
+  | { custom: bigint };
 export type GroupStatus = "Open" | "Active" | "Completed" | "Paused";
 
 export interface SdkConfig {
@@ -43,6 +50,8 @@ export interface CreateGroupParams {
   startTimestamp: bigint;
   /** Whether the group appears in public discovery listings. */
   isPublic: boolean;
+  /** Address that receives the group's platform fees. */
+  treasury: string;
 }
 
 /** Mirrors the `SavingsGroup` struct returned by `create_group`. */
@@ -66,6 +75,12 @@ export interface SavingsGroup {
  * The caller must be the group admin and provide a `signTransaction` callback.
  * The admin is automatically added as the first member on-chain.
  *
+ * The group is created in native XLM with the contract's default settings
+ * (`options` is passed as `None`); change them with the contract's setters
+ * while the group is Open. The contract's `create_group` takes `treasury`
+ * and an optional `GroupOptions` after `is_public`, replacing the earlier
+ * `token_address` argument, so older call shapes are rejected.
+ *
  * **Error variants surfaced by the contract:**
  * - `ContributionTooLow` — `contributionAmount` < 10 XLM (10_000_000 stroops)
  * - `InvalidMemberCount` — `totalMembers` not in [3, 20]
@@ -86,6 +101,7 @@ export interface SavingsGroup {
  *     frequency: "Monthly",
  *     startTimestamp: BigInt(Math.floor(Date.now() / 1000) + 86400),
  *     isPublic: true,
+ *     treasury: publicKey,
  *   },
  * );
  * ```
@@ -100,8 +116,15 @@ export async function createGroup(
   const contract = new Contract(contractId);
   const account = await server.getAccount(publicKey);
 
-  // Soroban enum variant must be encoded as a single-element Vec<Symbol>.
-  const frequencyScVal = xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(params.frequency)]);
+  // Soroban enum variants are encoded as a Vec of the variant Symbol
+  // followed by its payload, if any.
+  const frequencyScVal =
+    typeof params.frequency === "string"
+      ? xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(params.frequency)])
+      : xdr.ScVal.scvVec([
+          xdr.ScVal.scvSymbol("Custom"),
+          nativeToScVal(params.frequency.custom, { type: "u64" }),
+        ]);
 
   let tx = new TransactionBuilder(account, { fee: BASE_FEE, networkPassphrase })
     .addOperation(
@@ -115,6 +138,9 @@ export async function createGroup(
         frequencyScVal,
         nativeToScVal(params.startTimestamp, { type: "u64" }),
         nativeToScVal(params.isPublic, { type: "bool" }),
+        nativeToScVal(params.treasury, { type: "address" }),
+        // `Option::None` is encoded as ScVal void.
+        xdr.ScVal.scvVoid(),
       ),
     )
     .setTimeout(30)